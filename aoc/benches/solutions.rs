@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc::solutions;
+
+// `cargo bench -p aoc [-- <day>...]` times each solution on its own input, for every year. Every day is run
+// for at least a second (and at least three times) and the fastest run is reported.
+fn main() {
+    let days: Vec<u8> = env::args().skip(1).filter_map(|a| a.parse().ok()).collect();
+    for solution in solutions::solutions() {
+        let (year, day) = (solution.year(), solution.day());
+        if !days.is_empty() && !days.contains(&day) {
+            continue;
+        }
+        let label = solutions::label(year, day);
+        let Ok(input) = fs::read_to_string(solutions::input_path(year, day)) else {
+            println!("{}: no input", label);
+            continue;
+        };
+
+        let mut runs: Vec<Duration> = Vec::new();
+        let start = Instant::now();
+        while runs.len() < 3 || start.elapsed() < Duration::from_secs(1) {
+            let run = Instant::now();
+            solution.solve(&input);
+            runs.push(run.elapsed());
+        }
+        let fastest = runs.iter().min().unwrap();
+        let mean = runs.iter().sum::<Duration>() / runs.len() as u32;
+        println!(
+            "{}: fastest {:.2?}, mean {:.2?} ({} runs)",
+            label,
+            fastest,
+            mean,
+            runs.len()
+        );
+    }
+}
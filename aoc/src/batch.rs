@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::parallel;
+use aoc::solutions;
+
+// An input file found in a batch directory, laid out as <dir>/dayNN/<name>. Expected answers
+// are optional and live next to the input as <name>-answer-part1, <name>-answer-part2, like
+// day10's input-answer-part2.
+#[derive(Debug)]
+pub struct BatchInput {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: Vec<Option<String>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Cell {
+    Answer(String),
+    Correct(String),
+    Wrong { answer: String, expected: String },
+    Missing { expected: String },
+}
+
+pub fn find_inputs(dir: &Path, year: u16, days: &[u8]) -> Result<Vec<BatchInput>, String> {
+    let mut inputs = Vec::new();
+    for day in 1..=25u8 {
+        if !days.is_empty() && !days.contains(&day) {
+            continue;
+        }
+        let day_dir = dir.join(format!("day{:02}", day));
+        if !day_dir.is_dir() {
+            if days.contains(&day) {
+                return Err(format!("{} is not a directory", day_dir.display()));
+            }
+            continue;
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(&day_dir)
+            .map_err(|e| format!("{}: {}", day_dir.display(), e))?
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            if name.contains("-answer-part") {
+                continue;
+            }
+            let expected = (1..=2)
+                .map(|part| {
+                    let answer_path = day_dir.join(format!("{}-answer-part{}", name, part));
+                    fs::read_to_string(answer_path)
+                        .ok()
+                        .map(|answer| answer.trim_end().to_string())
+                })
+                .collect();
+            inputs.push(BatchInput {
+                year,
+                day,
+                name,
+                path,
+                expected,
+            });
+        }
+    }
+    Ok(inputs)
+}
+
+pub fn check_answers(answers: &[String], expected: &[Option<String>]) -> Vec<Cell> {
+    // up to the last part with an expected answer, even if an earlier one has none
+    let expected_parts = expected
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |i| i + 1);
+    let parts = answers.len().max(expected_parts);
+    (0..parts)
+        .map(|part| {
+            let expected = expected.get(part).cloned().flatten();
+            match (answers.get(part), expected) {
+                (Some(answer), None) => Cell::Answer(answer.clone()),
+                (Some(answer), Some(expected)) if *answer == expected => {
+                    Cell::Correct(answer.clone())
+                }
+                (Some(answer), Some(expected)) => Cell::Wrong {
+                    answer: answer.clone(),
+                    expected,
+                },
+                (None, Some(expected)) => Cell::Missing { expected },
+                (None, None) => Cell::Answer(String::new()),
+            }
+        })
+        .collect()
+}
+
+// Solves every input and prints an answer matrix per day. Returns false if any input panicked
+// or produced an answer that differs from its expected answer.
+pub fn run(inputs: &[BatchInput], jobs: usize) -> bool {
+    let results = parallel::map(inputs, jobs, |input| {
+        let label = solutions::label(input.year, input.day);
+        let solution = solutions::get(input.year, input.day)
+            .ok_or_else(|| format!("{} is not solved", label))?;
+        let text = fs::read_to_string(&input.path).map_err(|e| e.to_string())?;
+        solutions::solve(solution, &text)
+            .map(|result| check_answers(&result.answers, &input.expected))
+    });
+
+    let mut flags: Vec<String> = Vec::new();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut day = 0;
+    for (input, result) in inputs.iter().zip(&results) {
+        if input.day != day {
+            print_table(&rows);
+            rows.clear();
+            day = input.day;
+            rows.push(vec![
+                solutions::label(input.year, day),
+                "part 1".to_string(),
+                "part 2".to_string(),
+            ]);
+        }
+
+        let label = format!("{}/{}", solutions::label(input.year, input.day), input.name);
+        let mut row = vec![format!("  {}", input.name)];
+        match result {
+            Ok(cells) => {
+                for (i, cell) in cells.iter().enumerate() {
+                    row.push(format_cell(cell));
+                    match cell {
+                        Cell::Wrong { answer, .. } if answer.contains('\n') => flags.push(format!(
+                            "{}: part {} answer does not match expected",
+                            label,
+                            i + 1
+                        )),
+                        Cell::Wrong { answer, expected } => flags.push(format!(
+                            "{}: part {} answer {} does not match expected {}",
+                            label,
+                            i + 1,
+                            answer,
+                            expected
+                        )),
+                        Cell::Missing { expected } => flags.push(format!(
+                            "{}: part {} has no answer, expected {}",
+                            label,
+                            i + 1,
+                            expected
+                        )),
+                        _ => (),
+                    }
+                }
+            }
+            Err(e) => {
+                row.push("failed".to_string());
+                flags.push(format!("{}: {}", label, e));
+            }
+        }
+        rows.push(row);
+    }
+    print_table(&rows);
+
+    if !flags.is_empty() {
+        println!("\n{} problem(s):", flags.len());
+        for flag in &flags {
+            println!("  {}", flag);
+        }
+    }
+    flags.is_empty()
+}
+
+fn format_cell(cell: &Cell) -> String {
+    let short = |answer: &str| {
+        if answer.contains('\n') {
+            format!("[{} lines]", answer.lines().count())
+        } else {
+            answer.to_string()
+        }
+    };
+    match cell {
+        Cell::Answer(answer) => short(answer),
+        Cell::Correct(answer) => format!("{} ✓", short(answer)),
+        Cell::Wrong { answer, .. } => format!("{} ✗", short(answer)),
+        Cell::Missing { .. } => "- ✗".to_string(),
+    }
+}
+
+fn print_table(rows: &[Vec<String>]) {
+    if rows.is_empty() {
+        return;
+    }
+    let columns = rows.iter().map(|row| row.len()).max().unwrap();
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .filter_map(|row| row.get(c))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+    for row in rows {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(c, cell)| format!("{:<width$}", cell, width = widths[c]))
+            .collect();
+        println!("{}", line.join("   ").trim_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_check_answers() {
+        let answers = vec!["24000".to_string(), "45000".to_string()];
+        let expected = vec![Some("24000".to_string()), Some("45001".to_string())];
+        assert_eq!(
+            check_answers(&answers, &expected),
+            vec![
+                Cell::Correct("24000".to_string()),
+                Cell::Wrong {
+                    answer: "45000".to_string(),
+                    expected: "45001".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_answers_without_expected() {
+        let answers = vec!["2=-1=0".to_string()];
+        assert_eq!(
+            check_answers(&answers, &[None, None]),
+            vec![Cell::Answer("2=-1=0".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_check_answers_missing_after_unexpected() {
+        let expected = vec![None, Some("45000".to_string())];
+        assert_eq!(
+            check_answers(&[], &expected),
+            vec![
+                Cell::Answer(String::new()),
+                Cell::Missing {
+                    expected: "45000".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_inputs() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day01")).unwrap();
+        fs::create_dir_all(dir.join("day03")).unwrap();
+        fs::write(dir.join("day01/bob"), "1\n").unwrap();
+        fs::write(dir.join("day01/alice"), "1\n").unwrap();
+        fs::write(dir.join("day01/alice-answer-part1"), "24000\n").unwrap();
+        fs::write(dir.join("day03/alice"), "a\n").unwrap();
+
+        let inputs = find_inputs(&dir, 2022, &[]).unwrap();
+        let names: Vec<(u8, &str)> = inputs.iter().map(|i| (i.day, i.name.as_str())).collect();
+        assert_eq!(names, vec![(1, "alice"), (1, "bob"), (3, "alice")]);
+        assert_eq!(inputs[0].expected, vec![Some("24000".to_string()), None]);
+
+        let inputs = find_inputs(&dir, 2022, &[3]).unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert!(find_inputs(&dir, 2022, &[2]).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,6 @@
+pub mod lint;
+pub mod memory;
+pub mod parallel;
+pub mod solutions;
+
+pub use solutions::{DayResult, Solution};
@@ -0,0 +1,748 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::solutions;
+
+// Checks puzzle inputs before they reach a solver. The solvers assume well-formed input and
+// panic somewhere deep inside `run` otherwise, so the grammars below mirror what each day's
+// parser expects, including the fixed grid sizes some of them use.
+
+#[derive(Debug, PartialEq)]
+pub struct Problem {
+    // 1-based line number, or 0 if the problem concerns the whole file
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+type Problems = Vec<Problem>;
+
+fn problem(problems: &mut Problems, i: usize, message: impl Into<String>) {
+    problems.push(Problem {
+        line: i + 1,
+        message: message.into(),
+    });
+}
+
+fn file_problem(problems: &mut Problems, message: impl Into<String>) {
+    problems.push(Problem {
+        line: 0,
+        message: message.into(),
+    });
+}
+
+pub fn lint(year: u16, day: u8, input: &str) -> Problems {
+    let mut problems = check_whitespace(year, day, input);
+
+    // the grammars below work on normalised lines, so CRLF or trailing whitespace is only
+    // reported once
+    let mut lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        file_problem(&mut problems, "input is empty");
+        return problems;
+    }
+
+    match (year, day) {
+        (2022, 1) => day01(&lines, &mut problems),
+        (2022, 2) => day02(&lines, &mut problems),
+        (2022, 3) => day03(&lines, &mut problems),
+        (2022, 4) => day04(&lines, &mut problems),
+        (2022, 5) => day05(&lines, &mut problems),
+        (2022, 6) => day06(&lines, &mut problems),
+        (2022, 7) => day07(&lines, &mut problems),
+        (2022, 8) => day08(&lines, &mut problems),
+        (2022, 9) => day09(&lines, &mut problems),
+        (2022, 10) => day10(&lines, &mut problems),
+        (2022, 11) => day11(&lines, &mut problems),
+        (2022, 12) => day12(&lines, &mut problems),
+        (2022, 13) => day13(&lines, &mut problems),
+        (2022, 14) => day14(&lines, &mut problems),
+        (2022, 15) => day15(&lines, &mut problems),
+        (2022, 16) => day16(&lines, &mut problems),
+        (2022, 17) => day17(&lines, &mut problems),
+        (2022, 18) => day18(&lines, &mut problems),
+        (2022, 24) => day24(&lines, &mut problems),
+        (2022, 25) => day25(&lines, &mut problems),
+        _ => file_problem(
+            &mut problems,
+            format!("no grammar for {}", solutions::label(year, day)),
+        ),
+    }
+    problems
+}
+
+fn check_whitespace(year: u16, day: u8, input: &str) -> Problems {
+    let mut problems = Vec::new();
+    // day05's stack drawing is column aligned and may legitimately end in spaces
+    let drawing_lines = if (year, day) == (2022, 5) {
+        input
+            .lines()
+            .take_while(|line| !line.trim().is_empty())
+            .count()
+    } else {
+        0
+    };
+
+    let mut crlf = 0;
+    for (i, line) in input.split('\n').enumerate() {
+        let line = match line.strip_suffix('\r') {
+            Some(line) => {
+                crlf += 1;
+                if crlf == 1 {
+                    problem(&mut problems, i, "CRLF line ending");
+                }
+                line
+            }
+            None => line,
+        };
+        if i >= drawing_lines && line.ends_with(char::is_whitespace) {
+            problem(&mut problems, i, "trailing whitespace");
+        }
+    }
+    if crlf > 1 {
+        file_problem(
+            &mut problems,
+            format!("{} lines have CRLF line endings", crlf),
+        );
+    }
+
+    if !input.is_empty() && !input.ends_with('\n') {
+        file_problem(&mut problems, "missing final newline");
+    }
+    if input.ends_with("\n\n") || input.ends_with("\r\n\r\n") {
+        file_problem(&mut problems, "blank lines at end of file");
+    }
+    problems
+}
+
+// Parses a number, telling apart values that are out of range for the type the solver uses
+// from things that are not numbers at all.
+fn number<T: FromStr>(s: &str, i: usize, what: &str, problems: &mut Problems) -> Option<T> {
+    match s.parse::<T>() {
+        Ok(n) => Some(n),
+        Err(_) => {
+            let digits = s.strip_prefix('-').unwrap_or(s);
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                problem(problems, i, format!("{} {} is out of range", what, s));
+            } else {
+                problem(problems, i, format!("{} {:?} is not a number", what, s));
+            }
+            None
+        }
+    }
+}
+
+fn expect_prefix<'a>(
+    line: &'a str,
+    prefix: &str,
+    i: usize,
+    problems: &mut Problems,
+) -> Option<&'a str> {
+    let rest = line.strip_prefix(prefix);
+    if rest.is_none() {
+        problem(
+            problems,
+            i,
+            format!("expected {:?}, found {:?}", prefix, line),
+        );
+    }
+    rest
+}
+
+// Splits lines into blank-line separated blocks, returning the index of each block's first
+// line alongside it.
+fn blocks<'a>(lines: &'a [&'a str], problems: &mut Problems) -> Vec<(usize, &'a [&'a str])> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    for i in 0..=lines.len() {
+        if i == lines.len() || lines[i].is_empty() {
+            if i > start {
+                blocks.push((start, &lines[start..i]));
+            } else if i < lines.len() {
+                problem(problems, i, "unexpected blank line");
+            }
+            start = i + 1;
+        }
+    }
+    blocks
+}
+
+fn check_grid(lines: &[&str], allowed: &str, max: usize, problems: &mut Problems) {
+    let width = lines[0].chars().count();
+    for (i, line) in lines.iter().enumerate() {
+        if line.chars().count() != width {
+            problem(
+                problems,
+                i,
+                format!("row has length {}, expected {}", line.len(), width),
+            );
+        }
+        if let Some(c) = line.chars().find(|c| !allowed.contains(*c)) {
+            problem(problems, i, format!("unexpected character {:?}", c));
+        }
+    }
+    if width > max || lines.len() > max {
+        file_problem(
+            problems,
+            format!(
+                "grid is {}x{}, the solver supports at most {}x{}",
+                width,
+                lines.len(),
+                max,
+                max
+            ),
+        );
+    }
+}
+
+fn day01(lines: &[&str], problems: &mut Problems) {
+    for (start, block) in blocks(lines, problems) {
+        let mut total: u64 = 0;
+        for (j, line) in block.iter().enumerate() {
+            if let Some(calories) = number::<u64>(line, start + j, "calories", problems) {
+                total = total.checked_add(calories).unwrap_or_else(|| {
+                    problem(problems, start + j, "elf total overflows u64");
+                    0
+                });
+            }
+        }
+    }
+}
+
+fn day02(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        let valid = matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z']);
+        if !valid {
+            problem(
+                problems,
+                i,
+                format!("expected \"[ABC] [XYZ]\", found {:?}", line),
+            );
+        }
+    }
+}
+
+fn day03(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(c) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+            problem(problems, i, format!("unexpected item {:?}", c));
+        }
+        if line.len() % 2 != 0 {
+            problem(
+                problems,
+                i,
+                format!("rucksack has an odd number of items ({})", line.len()),
+            );
+        }
+    }
+    if !lines.len().is_multiple_of(3) {
+        file_problem(
+            problems,
+            format!(
+                "{} rucksacks cannot be split into groups of three",
+                lines.len()
+            ),
+        );
+    }
+}
+
+fn day04(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        let Some((a, b)) = line.split_once(',') else {
+            problem(
+                problems,
+                i,
+                format!("expected \"a-b,c-d\", found {:?}", line),
+            );
+            continue;
+        };
+        for range in [a, b] {
+            let Some((from, to)) = range.split_once('-') else {
+                problem(problems, i, format!("expected a range, found {:?}", range));
+                continue;
+            };
+            let from = number::<u32>(from, i, "section", problems);
+            let to = number::<u32>(to, i, "section", problems);
+            if let (Some(from), Some(to)) = (from, to) {
+                if from > to {
+                    problem(problems, i, format!("range {} is reversed", range));
+                }
+            }
+        }
+    }
+}
+
+fn day05(lines: &[&str], problems: &mut Problems) {
+    let Some(labels) = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with(|c: char| c.is_ascii_digit()))
+    else {
+        file_problem(problems, "missing stack numbers below the drawing");
+        return;
+    };
+
+    let stacks = lines[labels].split_whitespace().count();
+    for (j, label) in lines[labels].split_whitespace().enumerate() {
+        if label != (j + 1).to_string() {
+            problem(
+                problems,
+                labels,
+                format!("unexpected stack number {}", label),
+            );
+        }
+    }
+    for (i, line) in lines[..labels].iter().enumerate() {
+        for (j, crate_) in line.as_bytes().chunks(4).enumerate() {
+            let valid = matches!(
+                crate_,
+                [b' ', b' ', b' ', ..] | [b'[', b'A'..=b'Z', b']', ..]
+            );
+            if !valid || j >= stacks {
+                problem(problems, i, format!("unexpected crate in column {}", j + 1));
+            }
+        }
+    }
+
+    if lines.get(labels + 1) != Some(&"") {
+        problem(
+            problems,
+            labels + 1,
+            "expected a blank line after the drawing",
+        );
+    }
+    for (i, line) in lines.iter().enumerate().skip(labels + 2) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let [_move, amount, _from, from, _to, to] = words[..] else {
+            problem(problems, i, format!("expected a move, found {:?}", line));
+            continue;
+        };
+        if (_move, _from, _to) != ("move", "from", "to") {
+            problem(problems, i, format!("expected a move, found {:?}", line));
+            continue;
+        }
+        if number::<usize>(amount, i, "amount", problems) == Some(0) {
+            problem(problems, i, "moves zero crates");
+        }
+        for stack in [from, to] {
+            if let Some(stack) = number::<usize>(stack, i, "stack", problems) {
+                if stack == 0 || stack > stacks {
+                    problem(problems, i, format!("stack {} does not exist", stack));
+                }
+            }
+        }
+    }
+}
+
+fn day06(lines: &[&str], problems: &mut Problems) {
+    if lines.len() != 1 {
+        file_problem(problems, format!("expected 1 line, found {}", lines.len()));
+    }
+    if let Some(c) = lines[0].chars().find(|c| !c.is_ascii_lowercase()) {
+        problem(problems, 0, format!("unexpected character {:?}", c));
+    }
+    if lines[0].len() < 14 {
+        problem(
+            problems,
+            0,
+            "datastream is too short for a start-of-message marker",
+        );
+    }
+}
+
+fn day07(lines: &[&str], problems: &mut Problems) {
+    if lines[0] != "$ cd /" {
+        problem(problems, 0, "expected the first command to be \"$ cd /\"");
+    }
+    let mut depth = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            ["$", "cd", "/"] => depth = 0,
+            ["$", "cd", ".."] => {
+                if depth == 0 {
+                    problem(problems, i, "cd .. from the root directory");
+                }
+                depth -= 1;
+            }
+            ["$", "cd", _] => depth += 1,
+            ["$", "ls"] => (),
+            ["dir", _] => (),
+            [size, _] => {
+                number::<usize>(size, i, "file size", problems);
+            }
+            _ => problem(problems, i, format!("unexpected line {:?}", line)),
+        }
+        depth = depth.max(0);
+    }
+}
+
+fn day08(lines: &[&str], problems: &mut Problems) {
+    check_grid(lines, "0123456789", 100, problems);
+}
+
+fn day09(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        match line.split_once(' ') {
+            Some(("L" | "R" | "U" | "D", steps)) => {
+                number::<u8>(steps, i, "step count", problems);
+            }
+            _ => problem(problems, i, format!("expected a motion, found {:?}", line)),
+        }
+    }
+}
+
+fn day10(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        if *line == "noop" {
+            continue;
+        }
+        match line.strip_prefix("addx ") {
+            Some(v) => {
+                number::<i32>(v, i, "addx value", problems);
+            }
+            None => problem(problems, i, format!("unknown instruction {:?}", line)),
+        }
+    }
+}
+
+fn day11(lines: &[&str], problems: &mut Problems) {
+    let blocks = blocks(lines, problems);
+    let monkeys = blocks.len();
+    for (m, (start, block)) in blocks.into_iter().enumerate() {
+        if block.len() != 6 {
+            problem(
+                problems,
+                start,
+                format!("monkey has {} lines, expected 6", block.len()),
+            );
+            continue;
+        }
+        let i = start;
+        expect_prefix(block[0], &format!("Monkey {}:", m), i, problems);
+        if let Some(items) = expect_prefix(block[1], "  Starting items: ", i + 1, problems) {
+            for item in items.split(", ") {
+                number::<u64>(item, i + 1, "item", problems);
+            }
+        }
+        if let Some(op) = expect_prefix(block[2], "  Operation: new = old ", i + 2, problems) {
+            match op.split_once(' ') {
+                Some(("*", "old")) => (),
+                Some(("*" | "+", scalar)) => {
+                    number::<u64>(scalar, i + 2, "operand", problems);
+                }
+                _ => problem(problems, i + 2, format!("unknown operation {:?}", op)),
+            }
+        }
+        if let Some(test) = expect_prefix(block[3], "  Test: divisible by ", i + 3, problems) {
+            if number::<u64>(test, i + 3, "divisor", problems) == Some(0) {
+                problem(problems, i + 3, "divisor is zero");
+            }
+        }
+        for (j, prefix) in [
+            (4, "    If true: throw to monkey "),
+            (5, "    If false: throw to monkey "),
+        ] {
+            if let Some(target) = expect_prefix(block[j], prefix, i + j, problems) {
+                if let Some(target) = number::<usize>(target, i + j, "monkey", problems) {
+                    if target >= monkeys || target == m {
+                        problem(
+                            problems,
+                            i + j,
+                            format!("cannot throw to monkey {}", target),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn day12(lines: &[&str], problems: &mut Problems) {
+    check_grid(lines, "abcdefghijklmnopqrstuvwxyzSE", 80, problems);
+    for marker in ['S', 'E'] {
+        let count: usize = lines.iter().map(|l| l.matches(marker).count()).sum();
+        if count != 1 {
+            file_problem(
+                problems,
+                format!("expected exactly one {}, found {}", marker, count),
+            );
+        }
+    }
+}
+
+fn check_packet(packet: &str, i: usize, problems: &mut Problems) {
+    let mut depth = 0;
+    let mut number = String::new();
+    for c in packet.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        if !number.is_empty() {
+            // the packet reader handles at most two digits
+            if number.len() > 2 || number.parse::<u8>().is_err() {
+                problem(problems, i, format!("integer {} is out of range", number));
+            }
+            number.clear();
+        }
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' => (),
+            _ => problem(problems, i, format!("unexpected character {:?}", c)),
+        }
+        if depth < 0 {
+            break;
+        }
+    }
+    if depth != 0 || !packet.starts_with('[') || !packet.ends_with(']') {
+        problem(problems, i, "packet is not a single balanced list");
+    }
+}
+
+fn day13(lines: &[&str], problems: &mut Problems) {
+    let mut packets = 0;
+    for (start, block) in blocks(lines, problems) {
+        if block.len() != 2 {
+            problem(
+                problems,
+                start,
+                format!("expected a pair of packets, found {}", block.len()),
+            );
+        }
+        for (j, packet) in block.iter().enumerate() {
+            check_packet(packet, start + j, problems);
+        }
+        packets += block.len();
+    }
+    if !packets.is_multiple_of(2) {
+        file_problem(problems, format!("odd number of packets ({})", packets));
+    }
+}
+
+fn day14(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        let mut previous: Option<(usize, usize)> = None;
+        for point in line.split(" -> ") {
+            let Some((x, y)) = point.split_once(',') else {
+                problem(problems, i, format!("expected a point, found {:?}", point));
+                break;
+            };
+            let (Some(x), Some(y)) = (
+                number::<usize>(x, i, "x", problems),
+                number::<usize>(y, i, "y", problems),
+            ) else {
+                break;
+            };
+            // the cave is a 700x700 array and needs room below the lowest rock for the floor
+            if x == 0 || x >= 699 || y >= 697 {
+                problem(
+                    problems,
+                    i,
+                    format!("point {},{} is outside the cave", x, y),
+                );
+            }
+            if let Some((px, py)) = previous {
+                if px != x && py != y {
+                    problem(problems, i, format!("segment to {},{} is diagonal", x, y));
+                }
+            }
+            previous = Some((x, y));
+        }
+    }
+}
+
+fn day15(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        let fields: Option<Vec<&str>> = line
+            .strip_prefix("Sensor at x=")
+            .and_then(|s| s.split_once(", y="))
+            .and_then(|(x, s)| {
+                let (y, s) = s.split_once(": closest beacon is at x=")?;
+                let (bx, by) = s.split_once(", y=")?;
+                Some(vec![x, y, bx, by])
+            });
+        match fields {
+            Some(fields) => {
+                for field in fields {
+                    number::<i32>(field, i, "coordinate", problems);
+                }
+            }
+            None => problem(problems, i, format!("expected a sensor, found {:?}", line)),
+        }
+    }
+}
+
+fn day16(lines: &[&str], problems: &mut Problems) {
+    let mut names = HashSet::new();
+    let mut tunnels = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let parsed = line.strip_prefix("Valve ").and_then(|s| {
+            let (name, s) = s.split_once(" has flow rate=")?;
+            let (rate, s) = s.split_once("; ")?;
+            let s = s
+                .strip_prefix("tunnels lead to valves ")
+                .or_else(|| s.strip_prefix("tunnel leads to valve "))?;
+            Some((name, rate, s))
+        });
+        let Some((name, rate, to)) = parsed else {
+            problem(problems, i, format!("expected a valve, found {:?}", line));
+            continue;
+        };
+        if !names.insert(name) {
+            problem(problems, i, format!("valve {} is defined twice", name));
+        }
+        number::<usize>(rate, i, "flow rate", problems);
+        tunnels.extend(to.split(", ").map(|to| (i, to)));
+    }
+    for (i, to) in tunnels {
+        if !names.contains(to) {
+            problem(problems, i, format!("tunnel leads to unknown valve {}", to));
+        }
+    }
+    if !names.contains("AA") {
+        file_problem(problems, "missing start valve AA");
+    }
+}
+
+fn day17(lines: &[&str], problems: &mut Problems) {
+    if lines.len() != 1 {
+        file_problem(problems, format!("expected 1 line, found {}", lines.len()));
+    }
+    if let Some(c) = lines[0].chars().find(|c| *c != '<' && *c != '>') {
+        problem(problems, 0, format!("unexpected jet {:?}", c));
+    }
+}
+
+fn day18(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        let coordinates: Vec<&str> = line.split(',').collect();
+        if coordinates.len() != 3 {
+            problem(problems, i, format!("expected x,y,z, found {:?}", line));
+            continue;
+        }
+        for c in coordinates {
+            // the scan is 25 cubes wide, with a layer of air around the droplet
+            if let Some(c) = number::<usize>(c, i, "coordinate", problems) {
+                if c > 22 {
+                    problem(problems, i, format!("coordinate {} is out of range", c));
+                }
+            }
+        }
+    }
+}
+
+fn day24(lines: &[&str], problems: &mut Problems) {
+    check_grid(lines, "#.<>^v", usize::MAX, problems);
+    let last = lines.len() - 1;
+    for i in [0, last] {
+        if lines[i].matches('.').count() != 1 || lines[i].contains(['<', '>', '^', 'v']) {
+            problem(problems, i, "expected a wall with a single opening");
+        }
+    }
+    for (i, line) in lines.iter().enumerate().take(last).skip(1) {
+        if !line.starts_with('#') || !line.ends_with('#') {
+            problem(problems, i, "row is not enclosed by walls");
+        }
+    }
+}
+
+fn day25(lines: &[&str], problems: &mut Problems) {
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(c) = line.chars().find(|c| !"=-012".contains(*c)) {
+            problem(problems, i, format!("invalid SNAFU digit {:?}", c));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_whitespace() {
+        let problems = lint(2022, 2, "A X \r\nB Y\r\nC Z");
+        let messages: Vec<String> = problems.iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "line 1: CRLF line ending",
+                "line 1: trailing whitespace",
+                "2 lines have CRLF line endings",
+                "missing final newline"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day02_unknown_round() {
+        let problems = lint(2022, 2, "A X\nA Q\n");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 2);
+    }
+
+    #[test]
+    fn test_day03_group_count() {
+        let problems = lint(2022, 3, "abab\ncdcd\n");
+        assert_eq!(
+            problems[0].message,
+            "2 rucksacks cannot be split into groups of three"
+        );
+    }
+
+    #[test]
+    fn test_day04_out_of_range() {
+        let problems = lint(2022, 4, "2-4,6-8\n2-99999999999,4-5\n");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].to_string(),
+            "line 2: section 99999999999 is out of range"
+        );
+    }
+
+    #[test]
+    fn test_day13_odd_packet_count() {
+        let problems = lint(2022, 13, "[1,1]\n[1,2]\n\n[3]\n");
+        let messages: Vec<&str> = problems.iter().map(|p| p.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "expected a pair of packets, found 1",
+                "odd number of packets (3)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_checked_in_inputs() {
+        for day in 1..=25 {
+            let dir = solutions::day_dir(2022, day);
+            if !dir.is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy();
+                if !name.starts_with("input") || name.contains("-answer-") {
+                    continue;
+                }
+                let problems = lint(2022, day, &fs::read_to_string(&path).unwrap());
+                assert_eq!(problems, vec![], "{}", path.display());
+            }
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::solutions::{self, DayResult};
+use aoc::{lint, memory, parallel};
+
+mod batch;
+mod report;
+mod serve;
+mod watch;
+
+#[global_allocator]
+static ALLOC: memory::Counting = memory::Counting;
+
+const USAGE: &str = "usage: aoc [--year <year>] [--jobs <n>] [--memory] <day>...
+       aoc [--year <year>] [--jobs <n>] [--memory] --all
+       aoc [--year <year>] [--jobs <n>] batch <dir> [<day>...]
+       aoc [--year <year>] [--jobs <n>] report [--mask] [--output <file>]
+       aoc [--year <year>] lint [<day>...] [--input <file>]
+       aoc [--year <year>] watch <day> [--input <file>]
+       aoc [--year <year>] explain <day> [--steps <n>] [--input <file>]
+       aoc serve [--port <port>]
+
+Days are solved for their own dayNN/input file. With --memory the peak heap
+usage, number of allocations and an estimate of the stack usage is reported for
+each part.
+
+--year picks the Advent of Code year, 2022 by default. 2022's days live in
+dayNN/ at the top of the workspace, other years' in <year>/dayNN/.
+
+Batch mode solves every input in <dir>/dayNN/ and compares the answers with the
+optional <input>-answer-part1 and <input>-answer-part2 files next to it.
+
+Report solves all days and writes a Markdown table of their answers, timings
+and whether the answers match the ones asserted in the day's tests, to <file> or
+standard output. --mask hides the answers.
+
+Lint checks the input files of each day (or the given --input file) against the
+day's input grammar without solving anything.
+
+Watch re-solves a day whenever its input (by default dayNN/input) changes, and
+rebuilds the runner first when the day's source changes, showing how the answers
+and timings differ from the previous run.
+
+Explain narrates the first <n> (by default 10) steps a day's solver takes on its
+input, like the walkthroughs in the puzzle statements, to help track down where
+an answer goes wrong.
+
+Serve answers POST /days/<day>/parts/<part> (for 2022) and
+/years/<year>/days/<day>/parts/<part> requests on 127.0.0.1 (by default port
+2022) with the input as the body, replying with the answer and timing, or
+the error and any input problems lint finds, as JSON.
+
+Set AOC_TRACE=<file> to write trace events of the solvers, such as the number of
+states a search explored, to <file> as JSON lines.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut all = false;
+    let mut memory = false;
+    let mut batch_dir: Option<PathBuf> = None;
+    let mut lint = false;
+    let mut report = false;
+    let mut mask = false;
+    let mut output: Option<PathBuf> = None;
+    let mut watch = false;
+    let mut explain = false;
+    let mut steps: usize = 10;
+    let mut serve = false;
+    let mut port: u16 = 2022;
+    let mut input: Option<PathBuf> = None;
+    let mut year = solutions::DEFAULT_YEAR;
+    let mut jobs = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut day_args: Vec<&str> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--memory" => memory = true,
+            "--year" => {
+                year = args
+                    .next()
+                    .and_then(|y| y.parse().ok())
+                    .unwrap_or_else(|| usage_error("--year expects a year"));
+            }
+            "--jobs" | "-j" => {
+                jobs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage_error("--jobs expects a number"));
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "batch" if batch_dir.is_none() => {
+                let dir = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("batch expects a directory"));
+                batch_dir = Some(PathBuf::from(dir));
+            }
+            "lint" => lint = true,
+            "report" => report = true,
+            "--mask" => mask = true,
+            "--output" | "-o" => {
+                let path = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--output expects a file"));
+                output = Some(PathBuf::from(path));
+            }
+            "watch" => watch = true,
+            "explain" => explain = true,
+            "--steps" => {
+                steps = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage_error("--steps expects a number"));
+            }
+            "serve" => serve = true,
+            "--port" => {
+                port = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage_error("--port expects a port number"));
+            }
+            "--input" => {
+                let path = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--input expects a file"));
+                input = Some(PathBuf::from(path));
+            }
+            _ => day_args.push(arg),
+        }
+    }
+    // days are only known to be solved once the year is
+    let mut days: Vec<u8> = day_args.iter().map(|arg| parse_day(year, arg)).collect();
+
+    if lint {
+        if !run_lint(year, &days, input.as_deref()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if serve {
+        serve::run(port);
+    }
+
+    if report {
+        if !days.is_empty() {
+            usage_error("report always covers all days");
+        }
+        match output {
+            Some(path) => {
+                let base = path.parent().filter(|p| !p.as_os_str().is_empty());
+                let markdown = report::run(year, jobs, mask, base.unwrap_or(Path::new(".")));
+                if let Err(e) = fs::write(&path, markdown) {
+                    eprintln!("{}: {}", path.display(), e);
+                    process::exit(1);
+                }
+            }
+            None => print!("{}", report::run(year, jobs, mask, Path::new("."))),
+        }
+        return;
+    }
+
+    if explain {
+        let [day] = days[..] else {
+            usage_error("explain needs exactly one day");
+        };
+        let path = input.unwrap_or_else(|| solutions::input_path(year, day));
+        let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("{}: {}", path.display(), e);
+            process::exit(1);
+        });
+        match solutions::explain(solutions::get(year, day).unwrap(), &text, steps) {
+            Ok(narration) => {
+                for line in narration {
+                    println!("{}", line);
+                }
+            }
+            Err(e) => {
+                println!("{} failed: {}", solutions::label(year, day), e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if watch {
+        let [day] = days[..] else {
+            usage_error("watch needs exactly one day");
+        };
+        let input = input.unwrap_or_else(|| solutions::input_path(year, day));
+        if !input.is_file() {
+            usage_error(&format!("{} does not exist", input.display()));
+        }
+        watch::run(year, day, &input);
+    }
+
+    if let Some(dir) = batch_dir {
+        let inputs = batch::find_inputs(&dir, year, &days).unwrap_or_else(|e| usage_error(&e));
+        if inputs.is_empty() {
+            usage_error(&format!("no inputs found in {}", dir.display()));
+        }
+        if !batch::run(&inputs, jobs) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if all {
+        days = solutions::solutions()
+            .filter(|s| s.year() == year)
+            .map(|s| s.day())
+            .filter(|&day| {
+                let exists = solutions::input_path(year, day).is_file();
+                if !exists {
+                    eprintln!("skipping {}: no input", solutions::label(year, day));
+                }
+                exists
+            })
+            .collect();
+    } else if days.is_empty() {
+        usage_error("no day given");
+    }
+
+    let start = Instant::now();
+    let results = parallel::map(&days, jobs, |&day| (day, solve(year, day, memory)));
+    let wall_clock = start.elapsed();
+
+    let mut summed = Duration::ZERO;
+    let mut failed = false;
+    for (day, result) in &results {
+        match result {
+            Ok(result) => {
+                summed += result.elapsed;
+                print_result(result, memory);
+            }
+            Err(e) => {
+                failed = true;
+                println!("{} failed: {}", solutions::label(year, *day), e);
+            }
+        }
+    }
+
+    if all {
+        // the sum of each day's wall-clock solve time, which includes any time spent waiting for
+        // a CPU, so it only approximates the CPU time used
+        println!(
+            "\n{} days, {} worker threads: wall-clock {:.2?}, summed solve time {:.2?} ({:.1}x)",
+            results.len(),
+            jobs,
+            wall_clock,
+            summed,
+            summed.as_secs_f64() / wall_clock.as_secs_f64()
+        );
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn solve(year: u16, day: u8, memory: bool) -> Result<DayResult, String> {
+    let path = solutions::input_path(year, day);
+    let input = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let solution = solutions::get(year, day).unwrap();
+    if memory {
+        solutions::solve_measured(solution, &input)
+    } else {
+        solutions::solve(solution, &input)
+    }
+}
+
+// Lints the given input, or all of the days' own input files. Returns false if any problems
+// were found.
+fn run_lint(year: u16, days: &[u8], input: Option<&Path>) -> bool {
+    let mut files: Vec<(u8, PathBuf)> = Vec::new();
+    if let Some(input) = input {
+        match days {
+            [day] => files.push((*day, input.to_path_buf())),
+            _ => usage_error("--input needs exactly one day"),
+        }
+    } else {
+        for day in 1..=25 {
+            let dir = solutions::day_dir(year, day);
+            if (!days.is_empty() && !days.contains(&day)) || !dir.is_dir() {
+                continue;
+            }
+            let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| {
+                    let name = path.file_name().unwrap().to_string_lossy();
+                    name.starts_with("input") && !name.contains("-answer-")
+                })
+                .collect();
+            paths.sort();
+            files.extend(paths.into_iter().map(|path| (day, path)));
+        }
+    }
+
+    let mut clean = true;
+    for (day, path) in files {
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{}: {}", path.display(), e);
+                clean = false;
+                continue;
+            }
+        };
+        let problems = lint::lint(year, day, &input);
+        if problems.is_empty() {
+            println!("{}: ok", path.display());
+            continue;
+        }
+        clean = false;
+        println!("{}: {} problem(s)", path.display(), problems.len());
+        for problem in problems {
+            println!("  {}", problem);
+        }
+    }
+    clean
+}
+
+fn parse_day(year: u16, arg: &str) -> u8 {
+    match arg.parse::<u8>() {
+        Ok(day) if solutions::get(year, day).is_some() => day,
+        Ok(day) if (1..=25).contains(&day) => {
+            usage_error(&format!("{} is not solved", solutions::label(year, day)))
+        }
+        _ => usage_error(&format!("unknown argument: {}", arg)),
+    }
+}
+
+fn print_result(result: &DayResult, memory: bool) {
+    println!(
+        "{} ({:.2?})",
+        solutions::label(result.year, result.day),
+        result.elapsed
+    );
+    for (i, answer) in result.answers.iter().enumerate() {
+        let label = if result.answers.len() == 1 {
+            "answer".to_string()
+        } else {
+            format!("part {} answer", i + 1)
+        };
+        if answer.contains('\n') {
+            println!("  {}:", label);
+            for line in answer.lines() {
+                println!("    {}", line);
+            }
+        } else {
+            println!("  {}: {}", label, answer);
+        }
+    }
+    if memory {
+        for (i, usage) in result.usage.iter().enumerate() {
+            println!("  part {} memory: {}", i + 1, usage);
+        }
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
+}
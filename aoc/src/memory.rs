@@ -0,0 +1,238 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::hint::black_box;
+use std::ptr;
+use std::thread;
+
+use crate::parallel::STACK_SIZE;
+
+// A global allocator that counts the allocations of the current thread. Only binaries that
+// install it with #[global_allocator] get heap usage reports; otherwise all counts stay zero.
+pub struct Counting;
+
+// Counters are per thread so that days solved in parallel do not see each other's allocations.
+// Memory freed by another thread than the one that allocated it is not tracked precisely.
+struct Counters {
+    allocations: Cell<usize>,
+    allocated: Cell<usize>,
+    current: Cell<isize>,
+    peak: Cell<isize>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            allocated: Cell::new(0),
+            current: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+fn record_alloc(size: usize) {
+    let _ = COUNTERS.try_with(|c| {
+        c.allocations.set(c.allocations.get() + 1);
+        c.allocated.set(c.allocated.get() + size);
+        c.current.set(c.current.get() + size as isize);
+        c.peak.set(c.peak.get().max(c.current.get()));
+    });
+}
+
+fn record_dealloc(size: usize) {
+    let _ = COUNTERS.try_with(|c| c.current.set(c.current.get() - size as isize));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc(layout);
+        if !p.is_null() {
+            record_alloc(layout.size());
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc_zeroed(layout);
+        if !p.is_null() {
+            record_alloc(layout.size());
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, p: *mut u8, layout: Layout) {
+        System.dealloc(p, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, p: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(p, layout, new_size);
+        if !new.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new
+    }
+}
+
+// Whether the counting allocator is installed in this binary.
+pub fn counting() -> bool {
+    let before = COUNTERS.with(|c| c.allocations.get());
+    drop(black_box(Box::new(0u64)));
+    COUNTERS.with(|c| c.allocations.get()) > before
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    // number of allocations, reallocations included
+    pub allocations: usize,
+    // total number of bytes allocated
+    pub allocated: usize,
+    // the most heap in use at any one time, on top of what was in use beforehand
+    pub peak: usize,
+    // the deepest the stack got, if it was measured
+    pub stack: Option<usize>,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "peak heap {}, {} allocations ({} total)",
+            bytes(self.peak),
+            self.allocations,
+            bytes(self.allocated)
+        )?;
+        match self.stack {
+            Some(stack) if stack >= PAINTED => write!(f, ", stack > {}", bytes(PAINTED)),
+            Some(stack) => write!(f, ", stack ~{}", bytes(stack)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn bytes(n: usize) -> String {
+    match n {
+        0..=1023 => format!("{} B", n),
+        1024..=1048575 => format!("{:.1} KiB", n as f64 / 1024.0),
+        _ => format!("{:.1} MiB", n as f64 / 1048576.0),
+    }
+}
+
+// Runs `f` on the current thread, counting its heap usage.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let (allocations, allocated, current) = COUNTERS.with(|c| {
+        c.peak.set(c.current.get());
+        (c.allocations.get(), c.allocated.get(), c.current.get())
+    });
+    let result = f();
+    let usage = COUNTERS.with(|c| Usage {
+        allocations: c.allocations.get() - allocations,
+        allocated: c.allocated.get() - allocated,
+        peak: (c.peak.get() - current).max(0) as usize,
+        stack: None,
+    });
+    (result, usage)
+}
+
+// How much of the stack below the caller is painted; deeper stacks are only reported as deeper
+// than this.
+const PAINTED: usize = STACK_SIZE / 2;
+const PAINT: u64 = 0x5afe_57ac_c0ff_ee00;
+// leave the frames of paint() and lowest_used() themselves alone, including the red zone
+const SLACK: usize = 4096;
+
+// Like measure, but runs `f` on a fresh thread whose stack is painted with a known pattern
+// beforehand. The lowest address that no longer holds the pattern afterwards gives an estimate
+// of the deepest stack frame, which is where the days that keep large arrays on the stack show
+// up.
+pub fn measure_stack<R: Send>(f: impl FnOnce() -> R + Send) -> (R, Usage) {
+    thread::scope(|s| {
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(s, || {
+                let base = stack_pointer();
+                let (bottom, top) = paint();
+                let (result, mut usage) = measure(f);
+                usage.stack = Some(base - lowest_used(bottom, top));
+                (result, usage)
+            })
+            .unwrap()
+            .join()
+            .unwrap()
+    })
+}
+
+// roughly, the address of a local in the caller's frame
+#[inline(always)]
+fn stack_pointer() -> usize {
+    let marker = 0u8;
+    black_box(&marker) as *const u8 as usize
+}
+
+#[inline(never)]
+fn paint() -> (usize, usize) {
+    let top = (stack_pointer() - SLACK) & !7;
+    let bottom = top - PAINTED;
+    for addr in (bottom..top).step_by(8) {
+        // SAFETY: the thread's stack is STACK_SIZE bytes and mapped, and nothing lives below the
+        // current frame yet
+        unsafe { ptr::write_volatile(addr as *mut u64, PAINT) };
+    }
+    (bottom, top)
+}
+
+#[inline(never)]
+fn lowest_used(bottom: usize, top: usize) -> usize {
+    for addr in (bottom..top).step_by(8) {
+        // SAFETY: see paint(); the region is below this frame
+        if unsafe { ptr::read_volatile(addr as *const u64) } != PAINT {
+            return addr;
+        }
+    }
+    top
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        assert!(counting());
+        let (v, usage) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(100);
+            drop(black_box(vec![0u8; 4096]));
+            v.push(1);
+            v
+        });
+        assert_eq!(v, [1]);
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.allocated, 800 + 4096);
+        assert_eq!(usage.peak, 800 + 4096);
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(2048), "2.0 KiB");
+        assert_eq!(bytes(3 * 1048576), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_measure_stack() {
+        #[inline(never)]
+        fn deep() -> u8 {
+            let buf = black_box([1u8; 256 * 1024]);
+            buf[1000]
+        }
+        let (result, usage) = measure_stack(deep);
+        assert_eq!(result, 1);
+        let stack = usage.stack.unwrap();
+        assert!((256 * 1024..PAINTED).contains(&stack), "{}", stack);
+    }
+}
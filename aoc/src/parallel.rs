@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// Some days keep large grids on the stack, e.g. day14's 700x700 cave
+pub const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+// Runs `f` for every job on a fixed number of worker threads. Workers pull the next job index
+// from a shared counter, so a slow day does not hold up the others. Results are returned in
+// job order, regardless of the order in which they finish.
+pub fn map<T, R, F>(jobs: &[T], workers: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            let tx = tx.clone();
+            let next = &next;
+            let f = &f;
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(s, move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= jobs.len() {
+                        break;
+                    }
+                    tx.send((i, f(&jobs[i]))).unwrap();
+                })
+                .unwrap();
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<Option<R>> = (0..jobs.len()).map(|_| None).collect();
+    for (i, result) in rx {
+        results[i] = Some(result);
+    }
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_keeps_job_order() {
+        let jobs: Vec<u64> = (0..16).collect();
+        let results = map(&jobs, 4, |&j| {
+            // make the early jobs finish last
+            thread::sleep(Duration::from_millis(16 - j));
+            j * 2
+        });
+        assert_eq!(results, (0..16).map(|j| j * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_no_jobs() {
+        let results: Vec<u8> = map(&[] as &[u8], 4, |&j| j);
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use aoc::parallel;
+use aoc::solutions::{self, DayResult};
+
+use crate::batch::{self, Cell};
+
+const IMAGE_EXTENSIONS: [&str; 6] = ["png", "gif", "jpg", "jpeg", "svg", "webp"];
+
+// Solves every day and renders a Markdown report with a row per day, checked against the
+// answers asserted in the day's test_input_answer test. Multi-line answers like day10's CRT
+// image, and any images in the day's directory, get a section of their own below the table.
+// Links to images are relative to `base`, the directory the report is written to.
+pub fn run(year: u16, jobs: usize, mask: bool, base: &Path) -> String {
+    let days: Vec<u8> = solutions::solutions()
+        .filter(|s| s.year() == year)
+        .map(|s| s.day())
+        .collect();
+    let results = parallel::map(&days, jobs, |&day| {
+        let path = solutions::input_path(year, day);
+        let input = fs::read_to_string(&path).map_err(|_| "no input".to_string())?;
+        solutions::solve(solutions::get(year, day).unwrap(), &input)
+    });
+
+    let mut report = String::new();
+    let mut sections = String::new();
+    let mut passed = 0;
+    let mut solved = 0;
+    writeln!(report, "# Advent of Code {}\n", year).unwrap();
+    writeln!(report, "| Day | Title | Part 1 | Part 2 | Time | Status |").unwrap();
+    writeln!(report, "|----:|-------|--------|--------|-----:|--------|").unwrap();
+    for (&day, result) in days.iter().zip(&results) {
+        let title = solutions::get(year, day).unwrap().title();
+        let heading = format!("Day {}: {}", day, title);
+        let (answers, time, status) = match result {
+            Ok(result) => {
+                solved += 1;
+                let expected = asserted_answers(year, day);
+                let cells = batch::check_answers(&result.answers, &expected);
+                let status = status(&cells);
+                if status.starts_with('✓') {
+                    passed += 1;
+                }
+                let answers = result
+                    .answers
+                    .iter()
+                    .map(|answer| format_answer(answer, mask, &heading))
+                    .collect();
+                (answers, format!("{:.2?}", result.elapsed), status)
+            }
+            Err(e) => (Vec::new(), String::new(), escape(e)),
+        };
+        writeln!(
+            report,
+            "| {} | {} | {} | {} | {} | {} |",
+            day,
+            escape(title),
+            answers.first().map_or("", String::as_str),
+            answers.get(1).map_or("", String::as_str),
+            time,
+            status
+        )
+        .unwrap();
+
+        let images = images(year, day);
+        let pictures: Vec<&String> = match result {
+            Ok(DayResult { answers, .. }) if !mask => {
+                answers.iter().filter(|a| a.contains('\n')).collect()
+            }
+            _ => Vec::new(),
+        };
+        if pictures.is_empty() && images.is_empty() {
+            continue;
+        }
+        writeln!(sections, "\n## {}\n", heading).unwrap();
+        for picture in pictures {
+            writeln!(sections, "```text\n{}\n```\n", picture).unwrap();
+        }
+        for image in images {
+            let name = image.file_name().unwrap().to_string_lossy();
+            let link = relative_path(base, &image);
+            writeln!(sections, "![{}]({})\n", name, link.display()).unwrap();
+        }
+    }
+
+    writeln!(
+        report,
+        "\n{} of {} days solved, {} passing their tests.",
+        solved,
+        days.len(),
+        passed
+    )
+    .unwrap();
+    report.push_str(sections.trim_end());
+    report.push('\n');
+    report
+}
+
+fn status(cells: &[Cell]) -> String {
+    if cells
+        .iter()
+        .any(|c| matches!(c, Cell::Wrong { .. } | Cell::Missing { .. }))
+    {
+        "✗ fail".to_string()
+    } else if cells.iter().all(|c| matches!(c, Cell::Correct(_))) {
+        "✓ pass".to_string()
+    } else if cells.iter().any(|c| matches!(c, Cell::Correct(_))) {
+        "✓ pass (partly tested)".to_string()
+    } else {
+        "untested".to_string()
+    }
+}
+
+fn format_answer(answer: &str, mask: bool, heading: &str) -> String {
+    if mask {
+        "•••••".to_string()
+    } else if answer.contains('\n') {
+        format!("[see below](#{})", anchor(heading))
+    } else {
+        format!("`{}`", escape(answer))
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+// GitHub's heading anchors: lowercase, punctuation dropped and spaces turned into dashes
+fn anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+// Visualisation stills checked in next to a day's input.
+fn images(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut images: Vec<PathBuf> = fs::read_dir(solutions::day_dir(year, day))
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+        })
+        .collect();
+    images.sort();
+    images
+}
+
+// The answers asserted by a day's test_input_answer test, which look like
+// `assert_eq!(part1_answer, 67016);` or, for a single answer, `assert_eq!(answer, "2=-1=0");`.
+pub fn asserted_answers(year: u16, day: u8) -> Vec<Option<String>> {
+    let src = solutions::day_dir(year, day).join("src");
+    match fs::read_to_string(src.join("lib.rs")) {
+        Ok(source) => parse_asserted_answers(&source, &src),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn parse_asserted_answers(source: &str, src: &Path) -> Vec<Option<String>> {
+    let mut answers = vec![None, None];
+    let Some(start) = source.find("fn test_input_answer()") else {
+        return Vec::new();
+    };
+    let test = &source[start..];
+    let test = &test[..test.find("\n    }").unwrap_or(test.len())];
+    for line in test.lines() {
+        let Some(args) = line
+            .trim()
+            .strip_prefix("assert_eq!(")
+            .and_then(|l| l.strip_suffix(");"))
+        else {
+            continue;
+        };
+        let Some((name, value)) = args.split_once(", ") else {
+            continue;
+        };
+        let part = match name {
+            "part1_answer" | "answer" => 0,
+            "part2_answer" => 1,
+            _ => continue,
+        };
+        answers[part] = if let Some(file) = value
+            .strip_prefix("include_str!(\"")
+            .and_then(|v| v.strip_suffix("\")"))
+        {
+            fs::read_to_string(src.join(file))
+                .ok()
+                .map(|answer| answer.trim_end().to_string())
+        } else {
+            Some(value.trim_matches('"').to_string())
+        };
+    }
+    if answers[1].is_none() {
+        answers.pop();
+    }
+    answers
+}
+
+// The path to `to` from the directory `from`, or `to` itself if they have nothing in common.
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let (Ok(from), Ok(to)) = (from.canonicalize(), to.canonicalize()) else {
+        return to.to_path_buf();
+    };
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    if common <= 1 {
+        return to.iter().collect();
+    }
+    let mut path = PathBuf::new();
+    for _ in common..from.len() {
+        path.push("..");
+    }
+    path.extend(&to[common..]);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_asserted_answers() {
+        let source = "
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!(\"../input-example\"));
+        assert_eq!(part1_answer, 13140);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!(\"../input\"));
+        assert_eq!(part1_answer, 16480);
+        assert_eq!(part2_answer, include_str!(\"../input-answer-part2\"));
+    }
+";
+        let src = solutions::root().join("day10").join("src");
+        let answers = parse_asserted_answers(source, &src);
+        assert_eq!(answers[0].as_deref(), Some("16480"));
+        assert_eq!(answers[1].as_ref().unwrap().lines().count(), 6);
+    }
+
+    #[test]
+    fn test_asserted_answers() {
+        assert_eq!(
+            asserted_answers(2022, 5),
+            vec![Some("CNSZFDVLJ".to_string())],
+            "day05 only asserts part 1"
+        );
+        assert_eq!(
+            asserted_answers(2022, 25),
+            vec![Some("2=01-0-2-0=-0==-1=01".to_string())]
+        );
+        assert!(asserted_answers(2022, 24).is_empty());
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(
+            anchor("Day 10: Cathode-Ray Tube"),
+            "day-10-cathode-ray-tube"
+        );
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let root = solutions::root();
+        let input = root.join("day10").join("input");
+        assert_eq!(
+            relative_path(&root.join("aoc"), &input),
+            Path::new("../day10/input")
+        );
+        assert_eq!(relative_path(root, &input), Path::new("day10/input"));
+    }
+}
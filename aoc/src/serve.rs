@@ -0,0 +1,390 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::parallel::STACK_SIZE;
+use aoc::{lint, solutions};
+use trace::json_string;
+
+// inputs are tens of kilobytes; anything much bigger is a mistake
+const MAX_BODY: usize = 16 * 1024 * 1024;
+// the longest request or header line, so that a client cannot fill memory with one
+const MAX_LINE: usize = 8 * 1024;
+// connections served at once, each on a thread with a STACK_SIZE stack
+const MAX_HANDLERS: usize = 16;
+// how long a client may take to send its request or to take the response
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// A small HTTP/1.1 service around the solvers, for tools that would rather not link Rust:
+//
+//     POST /days/{n}/parts/{p}                with the puzzle input as the body
+//     POST /years/{y}/days/{n}/parts/{p}      the same for another year than 2022
+//
+// answers with JSON like {"day":1,"part":1,"answer":"24000","elapsed_us":112}, or with an
+// {"error":...} object and a 4xx status. When a solver chokes on its input the response also
+// lists the problems lint finds in it. Every connection is served by its own thread and closed
+// after one response. At most MAX_HANDLERS connections are served at once; further ones wait to
+// be accepted until one of them is done.
+pub fn run(port: u16) -> ! {
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap_or_else(|e| {
+        eprintln!("cannot listen on port {}: {}", port, e);
+        std::process::exit(1);
+    });
+    println!("listening on http://{}", listener.local_addr().unwrap());
+    serve(listener, MAX_HANDLERS)
+}
+
+fn serve(listener: TcpListener, max_handlers: usize) -> ! {
+    let slots = Arc::new(Slots {
+        free: Mutex::new(max_handlers),
+        freed: Condvar::new(),
+    });
+    loop {
+        // further connections wait to be accepted until a handler is free
+        let slot = Slot::take(&slots);
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                eprintln!("accept failed: {}", e);
+                continue;
+            }
+        };
+        // a client that stops sending or reading must not hold on to its handler forever
+        if stream.set_read_timeout(Some(TIMEOUT)).is_err()
+            || stream.set_write_timeout(Some(TIMEOUT)).is_err()
+        {
+            continue;
+        }
+        // solvers need the same large stack as the runner's workers
+        let spawned = thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(move || {
+                handle(stream);
+                drop(slot);
+            });
+        if let Err(e) = spawned {
+            eprintln!("cannot spawn a thread: {}", e);
+        }
+    }
+}
+
+// How many more connections can be served at once.
+struct Slots {
+    free: Mutex<usize>,
+    freed: Condvar,
+}
+
+// A connection being served, which frees its slot when dropped.
+struct Slot(Arc<Slots>);
+
+impl Slot {
+    fn take(slots: &Arc<Slots>) -> Slot {
+        let mut free = slots.free.lock().unwrap();
+        while *free == 0 {
+            free = slots.freed.wait(free).unwrap();
+        }
+        *free -= 1;
+        Slot(Arc::clone(slots))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap() += 1;
+        self.0.freed.notify_one();
+    }
+}
+
+fn handle(stream: TcpStream) {
+    let mut reader = BufReader::new(&stream);
+    let (status, body) = match read_request(&mut reader, &stream) {
+        Ok((method, path, body)) => respond(&method, &path, &body),
+        Err((status, message)) => (status, error(&message)),
+    };
+    write_response(&stream, status, &body);
+}
+
+fn write_response(mut stream: &TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+type Request = (String, String, Vec<u8>);
+
+fn read_request(
+    reader: &mut impl BufRead,
+    mut stream: &TcpStream,
+) -> Result<Request, (&'static str, String)> {
+    let bad_request = |message: &str| ("400 Bad Request", message.to_string());
+    let line = read_line(reader)
+        .map_err(|_| bad_request("cannot read request"))?
+        .ok_or_else(|| bad_request("request line is too long"))?;
+    let mut request_line = line.split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    let mut expect_continue = false;
+    loop {
+        let line = read_line(reader)
+            .map_err(|_| bad_request("cannot read headers"))?
+            .ok_or_else(|| {
+                (
+                    "431 Request Header Fields Too Large",
+                    "header is too long".to_string(),
+                )
+            })?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad_request("malformed header"));
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => {
+                length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| bad_request("bad Content-Length"))?
+            }
+            "expect" => expect_continue = value.trim().eq_ignore_ascii_case("100-continue"),
+            _ => {}
+        }
+    }
+    if length > MAX_BODY {
+        return Err(("413 Payload Too Large", "input is too large".to_string()));
+    }
+    // curl holds back larger bodies until the server agrees to take them
+    if expect_continue {
+        let _ = stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n");
+    }
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad_request("body is shorter than Content-Length"))?;
+    Ok((method, path, body))
+}
+
+// Reads a line of at most MAX_LINE bytes, or None if it is longer.
+fn read_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_LINE as u64 + 1)
+        .read_until(b'\n', &mut line)?;
+    if line.len() > MAX_LINE {
+        return Ok(None);
+    }
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// The status line and JSON body answering a request.
+fn respond(method: &str, path: &str, body: &[u8]) -> (&'static str, String) {
+    let not_found = (
+        "404 Not Found",
+        error(&format!("no such resource: {}", path)),
+    );
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments[..] {
+        ["days", day, "parts", part] => (Ok(solutions::DEFAULT_YEAR), day, part),
+        ["years", year, "days", day, "parts", part] => (year.parse::<u16>(), day, part),
+        _ => return not_found,
+    };
+    let (Ok(year), Ok(day), Ok(part)) = (year, day.parse::<u8>(), part.parse::<usize>()) else {
+        return not_found;
+    };
+    let label = solutions::label(year, day);
+    if method != "POST" {
+        let message = format!("{} is not supported, POST the input instead", method);
+        return ("405 Method Not Allowed", error(&message));
+    }
+    let Some(solution) = solutions::get(year, day) else {
+        return ("404 Not Found", error(&format!("{} is not solved", label)));
+    };
+    if part == 0 || part > solution.parts() {
+        let message = format!("{} has no part {}", label, part);
+        return ("404 Not Found", error(&message));
+    }
+    let Ok(input) = std::str::from_utf8(body) else {
+        return ("400 Bad Request", error("input is not UTF-8"));
+    };
+
+    let start = Instant::now();
+    let answer = solutions::solve_part(solution, part, input);
+    let elapsed = start.elapsed();
+    match answer {
+        Ok(answer) => (
+            "200 OK",
+            format!(
+                "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_us\":{}}}",
+                day,
+                part,
+                json_string(&answer),
+                elapsed.as_micros()
+            ),
+        ),
+        Err(e) => {
+            let problems: Vec<String> = lint::lint(year, day, input)
+                .iter()
+                .map(|p| {
+                    format!(
+                        "{{\"line\":{},\"message\":{}}}",
+                        p.line,
+                        json_string(&p.message)
+                    )
+                })
+                .collect();
+            (
+                "422 Unprocessable Entity",
+                format!(
+                    "{{\"day\":{},\"part\":{},\"error\":{},\"problems\":[{}]}}",
+                    day,
+                    part,
+                    json_string(&e),
+                    problems.join(",")
+                ),
+            )
+        }
+    }
+}
+
+fn error(message: &str) -> String {
+    format!("{{\"error\":{}}}", json_string(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+    use std::sync::mpsc;
+
+    fn start(max_handlers: usize) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, max_handlers));
+        addr
+    }
+
+    fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (String, String) {
+        send(
+            addr,
+            &format!(
+                "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+                method,
+                path,
+                body.len(),
+                body
+            ),
+        )
+    }
+
+    fn send(addr: SocketAddr, request: &str) -> (String, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.lines().next().unwrap();
+        (
+            status.trim_start_matches("HTTP/1.1 ").to_string(),
+            body.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_solve() {
+        let addr = start(MAX_HANDLERS);
+        let input = include_str!("../../day01/input-example");
+        let (status, body) = request(addr, "POST", "/days/1/parts/2", input);
+        assert_eq!(status, "200 OK");
+        assert!(
+            body.starts_with("{\"day\":1,\"part\":2,\"answer\":\"45000\",\"elapsed_us\":"),
+            "{}",
+            body
+        );
+
+        let (status, body) = request(addr, "POST", "/days/10/parts/2", "noop\n");
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"answer\":\"#\""), "{}", body);
+
+        let (status, body) = request(addr, "POST", "/years/2022/days/1/parts/1", input);
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"answer\":\"24000\""), "{}", body);
+    }
+
+    #[test]
+    fn test_errors() {
+        let addr = start(MAX_HANDLERS);
+        let (status, body) = request(addr, "POST", "/days/2/parts/1", "A X\nA Q\n");
+        assert_eq!(status, "422 Unprocessable Entity");
+        assert_eq!(
+            body,
+            "{\"day\":2,\"part\":1,\"error\":\"panicked: unknown input: A Q\",\
+             \"problems\":[{\"line\":2,\"message\":\"expected \\\"[ABC] [XYZ]\\\", found \\\"A Q\\\"\"}]}"
+        );
+
+        let (status, _) = request(addr, "POST", "/days/19/parts/1", "");
+        assert_eq!(status, "404 Not Found");
+        let (status, body) = request(addr, "POST", "/years/2015/days/1/parts/1", "");
+        assert_eq!(status, "404 Not Found");
+        assert_eq!(body, "{\"error\":\"2015/day01 is not solved\"}");
+        let (status, _) = request(addr, "POST", "/days/25/parts/2", "1=\n");
+        assert_eq!(status, "404 Not Found");
+        let (status, _) = request(addr, "GET", "/days/1/parts/1", "");
+        assert_eq!(status, "405 Method Not Allowed");
+        let (status, body) = request(addr, "POST", "/", "");
+        assert_eq!(status, "404 Not Found");
+        assert_eq!(body, "{\"error\":\"no such resource: /\"}");
+    }
+
+    #[test]
+    fn test_long_lines() {
+        let addr = start(MAX_HANDLERS);
+        // one byte over, with nothing after it, so that the server has read everything sent
+        let (status, body) = send(addr, &"P".repeat(MAX_LINE + 1));
+        assert_eq!(status, "400 Bad Request");
+        assert_eq!(body, "{\"error\":\"request line is too long\"}");
+
+        let request_line = "POST /days/1/parts/1 HTTP/1.1\r\n";
+        let header = format!("X-Padding: {}", "x".repeat(MAX_LINE + 1 - 11));
+        let (status, body) = send(addr, &format!("{}{}", request_line, header));
+        assert_eq!(status, "431 Request Header Fields Too Large");
+        assert_eq!(body, "{\"error\":\"header is too long\"}");
+
+        let header = format!("X-Padding: {}\r\n", "x".repeat(MAX_LINE - 13));
+        let (status, _) = send(
+            addr,
+            &format!("{}{}Content-Length: 2\r\n\r\n1\n", request_line, header),
+        );
+        assert_eq!(status, "200 OK");
+    }
+
+    #[test]
+    fn test_too_many_connections() {
+        let addr = start(1);
+        // holds the only handler while it waits for a request
+        let idle = TcpStream::connect(addr).unwrap();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || tx.send(request(addr, "POST", "/days/1/parts/1", "1\n")));
+        thread::sleep(Duration::from_millis(100));
+        assert!(rx.try_recv().is_err(), "served while the handler was busy");
+
+        // once it is closed the handler is free again
+        drop(idle);
+        let (status, body) = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"answer\":\"1\""), "{}", body);
+    }
+}
@@ -0,0 +1,499 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use crate::memory::{self, Usage};
+
+// A puzzle solver that can be driven by the runner. Parts are numbered from 1 and their answers
+// are rendered to strings, so days with different answer types can be handled alike.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    // the puzzle's title, as on the puzzle page
+    fn title(&self) -> &str;
+
+    fn parts(&self) -> usize;
+
+    fn solve_part(&self, part: usize, input: &str) -> String;
+
+    // Narrates the first `steps` steps of solving the puzzle, one line each, like the walkthroughs
+    // in the puzzle statements. Mostly of part 1, where the walkthroughs are.
+    fn explain(&self, input: &str, steps: usize) -> Vec<String>;
+
+    fn solve(&self, input: &str) -> Vec<String> {
+        (1..=self.parts())
+            .map(|part| self.solve_part(part, input))
+            .collect()
+    }
+}
+
+struct Day {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    parts: &'static [fn(&str) -> String],
+    explain: fn(&str, usize) -> Vec<String>,
+}
+
+impl Solution for Day {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> &str {
+        self.title
+    }
+
+    fn parts(&self) -> usize {
+        self.parts.len()
+    }
+
+    fn solve_part(&self, part: usize, input: &str) -> String {
+        (self.parts[part - 1])(input)
+    }
+
+    fn explain(&self, input: &str, steps: usize) -> Vec<String> {
+        (self.explain)(input, steps)
+    }
+}
+
+static SOLUTIONS: [Day; 20] = [
+    Day {
+        year: 2022,
+        day: 1,
+        title: "Calorie Counting",
+        parts: &[
+            |input| day01::part1(input).to_string(),
+            |input| day01::part2(input).to_string(),
+        ],
+        explain: day01::explain,
+    },
+    Day {
+        year: 2022,
+        day: 2,
+        title: "Rock Paper Scissors",
+        parts: &[
+            |input| day02::part1(input).to_string(),
+            |input| day02::part2(input).to_string(),
+        ],
+        explain: day02::explain,
+    },
+    Day {
+        year: 2022,
+        day: 3,
+        title: "Rucksack Reorganization",
+        parts: &[
+            |input| day03::part1(input).to_string(),
+            |input| day03::part2(input).to_string(),
+        ],
+        explain: day03::explain,
+    },
+    Day {
+        year: 2022,
+        day: 4,
+        title: "Camp Cleanup",
+        parts: &[
+            |input| day04::part1(input).to_string(),
+            |input| day04::part2(input).to_string(),
+        ],
+        explain: day04::explain,
+    },
+    Day {
+        year: 2022,
+        day: 5,
+        title: "Supply Stacks",
+        parts: &[
+            |input| day05::part1(input).to_string(),
+            |input| day05::part2(input).to_string(),
+        ],
+        explain: day05::explain,
+    },
+    Day {
+        year: 2022,
+        day: 6,
+        title: "Tuning Trouble",
+        parts: &[
+            |input| day06::part1(input).to_string(),
+            |input| day06::part2(input).to_string(),
+        ],
+        explain: day06::explain,
+    },
+    Day {
+        year: 2022,
+        day: 7,
+        title: "No Space Left On Device",
+        parts: &[
+            |input| day07::part1(input).to_string(),
+            |input| day07::part2(input).to_string(),
+        ],
+        explain: day07::explain,
+    },
+    Day {
+        year: 2022,
+        day: 8,
+        title: "Treetop Tree House",
+        parts: &[
+            |input| day08::part1(input).to_string(),
+            |input| day08::part2(input).to_string(),
+        ],
+        explain: day08::explain,
+    },
+    Day {
+        year: 2022,
+        day: 9,
+        title: "Rope Bridge",
+        parts: &[
+            |input| day09::part1(input).to_string(),
+            |input| day09::part2(input).to_string(),
+        ],
+        explain: day09::explain,
+    },
+    Day {
+        year: 2022,
+        day: 10,
+        title: "Cathode-Ray Tube",
+        parts: &[
+            |input| day10::part1(input).to_string(),
+            |input| day10::part2(input).to_string(),
+        ],
+        explain: day10::explain,
+    },
+    Day {
+        year: 2022,
+        day: 11,
+        title: "Monkey in the Middle",
+        parts: &[
+            |input| day11::part1(input).to_string(),
+            |input| day11::part2(input).to_string(),
+        ],
+        explain: day11::explain,
+    },
+    Day {
+        year: 2022,
+        day: 12,
+        title: "Hill Climbing Algorithm",
+        parts: &[
+            |input| day12::part1(input).to_string(),
+            |input| day12::part2(input).to_string(),
+        ],
+        explain: day12::explain,
+    },
+    Day {
+        year: 2022,
+        day: 13,
+        title: "Distress Signal",
+        parts: &[
+            |input| day13::part1(input).to_string(),
+            |input| day13::part2(input).to_string(),
+        ],
+        explain: day13::explain,
+    },
+    Day {
+        year: 2022,
+        day: 14,
+        title: "Regolith Reservoir",
+        parts: &[
+            |input| day14::part1(input).to_string(),
+            |input| day14::part2(input).to_string(),
+        ],
+        explain: day14::explain,
+    },
+    Day {
+        year: 2022,
+        day: 15,
+        title: "Beacon Exclusion Zone",
+        parts: &[
+            |input| day15::part1(input, 2000000).to_string(),
+            |input| day15::part2(input, 4000000).to_string(),
+        ],
+        explain: |input, steps| day15::explain(input, 2000000, steps),
+    },
+    Day {
+        year: 2022,
+        day: 16,
+        title: "Proboscidea Volcanium",
+        parts: &[
+            |input| day16::part1(input).to_string(),
+            |input| day16::part2(input).to_string(),
+        ],
+        explain: day16::explain,
+    },
+    Day {
+        year: 2022,
+        day: 17,
+        title: "Pyroclastic Flow",
+        parts: &[
+            |input| day17::part1(input).to_string(),
+            |input| day17::part2(input).to_string(),
+        ],
+        explain: day17::explain,
+    },
+    Day {
+        year: 2022,
+        day: 18,
+        title: "Boiling Boulders",
+        parts: &[
+            |input| day18::part1(input).to_string(),
+            |input| day18::part2(input).to_string(),
+        ],
+        explain: day18::explain,
+    },
+    Day {
+        year: 2022,
+        day: 24,
+        title: "Blizzard Basin",
+        parts: &[
+            |input| day24::part1(input).to_string(),
+            |input| day24::part2(input).to_string(),
+        ],
+        explain: day24::explain,
+    },
+    Day {
+        year: 2022,
+        day: 25,
+        title: "Full of Hot Air",
+        parts: &[day25::part1],
+        explain: day25::explain,
+    },
+];
+
+// The year whose days live at the top level of the workspace, and the one the runner picks when
+// no year is given. Other years live in <year>/dayNN directories.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+pub fn solutions() -> impl Iterator<Item = &'static dyn Solution> {
+    SOLUTIONS.iter().map(|s| s as &dyn Solution)
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = solutions().map(|s| s.year()).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    solutions().find(|s| s.year() == year && s.day() == day)
+}
+
+// the workspace root, where the dayNN and <year> directories live
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    root().join(label(year, day))
+}
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input")
+}
+
+// How a day is referred to in output: its directory relative to the workspace root, so "day01"
+// or "2021/day01".
+pub fn label(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{:02}", day)
+    } else {
+        format!("{}/day{:02}", year, day)
+    }
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub answers: Vec<String>,
+    pub elapsed: Duration,
+    // memory usage of each part; heap counts are only filled in when the binary installs
+    // memory::Counting
+    pub usage: Vec<Usage>,
+}
+
+thread_local! {
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Solves the input part by part, turning a panicking solver into an error. The panic message is
+// reported through the error rather than printed by the panic hook.
+pub fn solve(solution: &dyn Solution, input: &str) -> Result<DayResult, String> {
+    solve_parts(solution, input, false)
+}
+
+// Like solve, but also estimates the stack usage of each part, see memory::measure_stack.
+pub fn solve_measured(solution: &dyn Solution, input: &str) -> Result<DayResult, String> {
+    solve_parts(solution, input, true)
+}
+
+// Solves a single part, see solve.
+pub fn solve_part(solution: &dyn Solution, part: usize, input: &str) -> Result<String, String> {
+    if !(1..=solution.parts()).contains(&part) {
+        let label = label(solution.year(), solution.day());
+        return Err(format!("{} has no part {}", label, part));
+    }
+
+    let answer = catch_panic(|| solution.solve_part(part, input))?;
+    // multi-line answers like day10's end with a newline, which is not part of the answer
+    Ok(answer.trim_end().to_string())
+}
+
+// Narrates the first `steps` steps of solving the input, see Solution::explain. Like solve, a
+// panic is turned into an error.
+pub fn explain(solution: &dyn Solution, input: &str, steps: usize) -> Result<Vec<String>, String> {
+    catch_panic(|| solution.explain(input, steps))
+}
+
+// Runs a solver, turning a panic into an error with its message and keeping the panic hook quiet.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SOLVING.with(Cell::get) {
+                default_hook(info);
+            }
+        }));
+    });
+
+    SOLVING.with(|s| s.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SOLVING.with(|s| s.set(false));
+    result.map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())))
+}
+
+fn solve_parts(solution: &dyn Solution, input: &str, stack: bool) -> Result<DayResult, String> {
+    let mut result = DayResult {
+        year: solution.year(),
+        day: solution.day(),
+        answers: Vec::new(),
+        elapsed: Duration::ZERO,
+        usage: Vec::new(),
+    };
+    for part in 1..=solution.parts() {
+        let run = || {
+            let _span = trace::span!(
+                "part",
+                year = solution.year(),
+                day = solution.day(),
+                part = part
+            );
+            let start = Instant::now();
+            let answer = solve_part(solution, part, input);
+            (answer, start.elapsed())
+        };
+        let ((answer, elapsed), usage) = if stack {
+            memory::measure_stack(run)
+        } else {
+            memory::measure(run)
+        };
+        result.answers.push(answer?);
+        result.elapsed += elapsed;
+        result.usage.push(usage);
+    }
+    Ok(result)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(2022, 1).unwrap().day(), 1);
+        assert!(get(2022, 19).is_none());
+        assert!(get(2021, 1).is_none());
+        assert_eq!(years(), [2022]);
+    }
+
+    #[test]
+    fn test_day_dir() {
+        assert_eq!(day_dir(2022, 1), root().join("day01"));
+        assert_eq!(day_dir(2021, 15), root().join("2021").join("day15"));
+        assert_eq!(label(2021, 15), "2021/day15");
+    }
+
+    #[test]
+    fn test_solve() {
+        let result = solve(
+            get(2022, 1).unwrap(),
+            include_str!("../../day01/input-example"),
+        )
+        .unwrap();
+        assert_eq!(result.answers, vec!["24000", "45000"]);
+        assert_eq!(result.usage.len(), 2);
+    }
+
+    #[test]
+    fn test_solve_measured() {
+        let result = solve_measured(get(2022, 25).unwrap(), "1=\n").unwrap();
+        assert_eq!(result.answers, vec!["1="]);
+        assert!(result.usage[0].stack.is_some());
+    }
+
+    #[test]
+    fn test_solve_panic() {
+        let error = solve(get(2022, 2).unwrap(), "A X\nA Q\n").unwrap_err();
+        assert_eq!(error, "panicked: unknown input: A Q");
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let input = include_str!("../../day01/input-example");
+        assert_eq!(
+            solve_part(get(2022, 1).unwrap(), 2, input).unwrap(),
+            "45000"
+        );
+        assert_eq!(
+            solve_part(get(2022, 25).unwrap(), 2, input).unwrap_err(),
+            "day25 has no part 2"
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = include_str!("../../day01/input-example");
+        let steps = explain(get(2022, 1).unwrap(), input, 2).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            steps[0],
+            "elf 1 carries 1000 + 2000 + 3000 = 6000 Calories, the most so far is 6000"
+        );
+        assert_eq!(
+            explain(get(2022, 2).unwrap(), "A Q\n", 5).unwrap_err(),
+            "panicked: unknown input: A Q"
+        );
+    }
+
+    #[test]
+    fn test_explain_is_bounded() {
+        for solution in solutions() {
+            let label = label(solution.year(), solution.day());
+            // day24's input is not checked in
+            let path = input_path(solution.year(), solution.day());
+            let Ok(input) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            let steps = explain(solution, &input, 3).unwrap();
+            assert!((1..=3).contains(&steps.len()), "{}: {:?}", label, steps);
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc::solutions::{self, DayResult};
+
+// Re-solves a day whenever its input file changes. The solvers are linked into the runner, so
+// when the day's source changes the runner is rebuilt and restarted instead. Each run is
+// compared with the previous one.
+pub fn run(year: u16, day: u8, input: &Path) -> ! {
+    let src = solutions::day_dir(year, day).join("src");
+    let input = input.canonicalize().unwrap_or_else(|_| input.to_path_buf());
+    let input_dir = input.parent().unwrap().to_path_buf();
+
+    let (tx, rx) = mpsc::channel();
+    watch_dirs(vec![input_dir, src.clone()], tx);
+
+    println!("watching {} and {}", input.display(), src.display());
+    let mut previous = solve(year, day, &input, None);
+    loop {
+        let mut changed: Vec<PathBuf> = vec![rx.recv().unwrap()];
+        // editors tend to write a file in several steps, so wait for things to settle down
+        while let Ok(path) = rx.recv_timeout(Duration::from_millis(200)) {
+            changed.push(path);
+        }
+
+        if changed.iter().any(|path| path.starts_with(&src)) {
+            println!("\nsource changed, rebuilding");
+            if build() {
+                restart();
+            }
+        } else if changed.contains(&input) {
+            println!(
+                "\ninput changed, re-solving {}",
+                solutions::label(year, day)
+            );
+            previous = solve(year, day, &input, previous.as_ref());
+        }
+    }
+}
+
+fn solve(year: u16, day: u8, input: &Path, previous: Option<&DayResult>) -> Option<DayResult> {
+    let result = fs::read_to_string(input)
+        .map_err(|e| e.to_string())
+        .and_then(|text| solutions::solve(solutions::get(year, day).unwrap(), &text));
+    match result {
+        Ok(result) => {
+            for line in diff(previous, &result) {
+                println!("  {}", line);
+            }
+            Some(result)
+        }
+        Err(e) => {
+            println!("  failed: {}", e);
+            None
+        }
+    }
+}
+
+fn build() -> bool {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["build", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.current_dir(solutions::root());
+    match command.status() {
+        Ok(status) if status.success() => true,
+        Ok(status) => {
+            println!("  build failed ({})", status);
+            false
+        }
+        Err(e) => {
+            println!("  could not run cargo: {}", e);
+            false
+        }
+    }
+}
+
+// Replaces this process with the freshly built runner, watching the same day and input.
+fn restart() -> ! {
+    let mut command = Command::new(env::current_exe().unwrap());
+    command.args(env::args_os().skip(1));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let e = command.exec();
+        println!("  could not restart: {}", e);
+        process::exit(1);
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status().unwrap();
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+pub fn diff(previous: Option<&DayResult>, current: &DayResult) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (i, answer) in current.answers.iter().enumerate() {
+        let label = format!("part {} answer:", i + 1);
+        let old = previous.and_then(|p| p.answers.get(i));
+        let answer = if answer.contains('\n') {
+            format!("\n{}", answer)
+        } else {
+            answer.clone()
+        };
+        match old {
+            None => lines.push(format!("{} {}", label, answer)),
+            Some(old) if old.trim_start() == answer.trim_start() => {
+                lines.push(format!("{} {} (unchanged)", label, answer))
+            }
+            Some(old) => lines.push(format!("{} {} (was {})", label, answer, old)),
+        }
+    }
+
+    let elapsed = current.elapsed;
+    match previous {
+        Some(previous) if !previous.elapsed.is_zero() => {
+            let change = elapsed.as_secs_f64() / previous.elapsed.as_secs_f64() - 1.0;
+            lines.push(format!(
+                "time: {:.2?} (was {:.2?}, {:+.0}%)",
+                elapsed,
+                previous.elapsed,
+                change * 100.0
+            ));
+        }
+        _ => lines.push(format!("time: {:.2?}", elapsed)),
+    }
+    lines
+}
+
+#[cfg(target_os = "linux")]
+fn watch_dirs(dirs: Vec<PathBuf>, tx: mpsc::Sender<PathBuf>) {
+    use std::ffi::CString;
+    use std::fs::File;
+    use std::io::Read;
+    use std::os::raw::{c_char, c_int};
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::io::FromRawFd;
+
+    // from <sys/inotify.h>; std already links against libc
+    extern "C" {
+        fn inotify_init1(flags: c_int) -> c_int;
+        fn inotify_add_watch(fd: c_int, pathname: *const c_char, mask: u32) -> c_int;
+    }
+    const IN_CLOEXEC: c_int = 0o2000000;
+    const IN_MODIFY: u32 = 0x002;
+    const IN_CLOSE_WRITE: u32 = 0x008;
+    const IN_MOVED_TO: u32 = 0x080;
+    const IN_CREATE: u32 = 0x100;
+    // wd, mask, cookie and len, followed by a NUL padded name of `len` bytes
+    const EVENT_HEADER: usize = 16;
+
+    let fd = unsafe { inotify_init1(IN_CLOEXEC) };
+    assert!(fd >= 0, "inotify_init1 failed");
+    let mut watches: Vec<(i32, PathBuf)> = Vec::new();
+    for dir in dirs {
+        let path = CString::new(dir.as_os_str().as_bytes()).unwrap();
+        let mask = IN_MODIFY | IN_CLOSE_WRITE | IN_MOVED_TO | IN_CREATE;
+        let wd = unsafe { inotify_add_watch(fd, path.as_ptr(), mask) };
+        assert!(wd >= 0, "cannot watch {}", dir.display());
+        watches.push((wd, dir));
+    }
+
+    let mut file = unsafe { File::from_raw_fd(fd) };
+    thread::spawn(move || {
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = file.read(&mut buf).unwrap();
+            let mut offset = 0;
+            while offset + EVENT_HEADER <= n {
+                let field = |i: usize| {
+                    let bytes = buf[offset + i * 4..offset + i * 4 + 4].try_into().unwrap();
+                    u32::from_ne_bytes(bytes)
+                };
+                let wd = field(0) as i32;
+                let len = field(3) as usize;
+                let name = &buf[offset + EVENT_HEADER..offset + EVENT_HEADER + len];
+                let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(len)];
+                if let Some((_, dir)) = watches.iter().find(|(w, _)| *w == wd) {
+                    let path = dir.join(std::ffi::OsStr::from_bytes(name));
+                    if tx.send(path).is_err() {
+                        return;
+                    }
+                }
+                offset += EVENT_HEADER + len;
+            }
+        }
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+fn watch_dirs(dirs: Vec<PathBuf>, tx: mpsc::Sender<PathBuf>) {
+    use std::collections::HashMap;
+    use std::time::SystemTime;
+
+    // no inotify, so poll the modification times instead
+    let scan = move || {
+        let mut mtimes: HashMap<PathBuf, SystemTime> = HashMap::new();
+        for dir in &dirs {
+            for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+                if let Ok(mtime) = entry.metadata().and_then(|m| m.modified()) {
+                    mtimes.insert(entry.path(), mtime);
+                }
+            }
+        }
+        mtimes
+    };
+    thread::spawn(move || {
+        let mut mtimes = scan();
+        loop {
+            thread::sleep(Duration::from_millis(500));
+            let current = scan();
+            for (path, mtime) in &current {
+                if mtimes.get(path) != Some(mtime) && tx.send(path.clone()).is_err() {
+                    return;
+                }
+            }
+            mtimes = current;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(answers: &[&str], millis: u64) -> DayResult {
+        DayResult {
+            year: 2022,
+            day: 1,
+            answers: answers.iter().map(|a| a.to_string()).collect(),
+            elapsed: Duration::from_millis(millis),
+            usage: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_first_run() {
+        let current = result(&["24000", "45000"], 10);
+        assert_eq!(
+            diff(None, &current),
+            vec![
+                "part 1 answer: 24000",
+                "part 2 answer: 45000",
+                "time: 10.00ms"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = result(&["24000", "45000"], 10);
+        let current = result(&["24000", "41000"], 15);
+        assert_eq!(
+            diff(Some(&previous), &current),
+            vec![
+                "part 1 answer: 24000 (unchanged)",
+                "part 2 answer: 41000 (was 45000)",
+                "time: 15.00ms (was 10.00ms, +50%)"
+            ]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_watch_dirs() {
+        use std::fs;
+
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (tx, rx) = mpsc::channel();
+        watch_dirs(vec![dir.clone()], tx);
+        fs::write(dir.join("input"), "1\n").unwrap();
+        let path = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(path, dir.join("input"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+use std::process::Command;
+
+// The solvers must build without std, which the no_std crate at the top of the repository checks.
+// It is not a workspace member, so it is built here into a target directory of its own.
+#[test]
+fn test_no_std_build() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--manifest-path"])
+        .arg(root.join("no_std/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(root.join("target/no_std"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
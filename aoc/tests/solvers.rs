@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+
+use aoc::solutions;
+
+#[test]
+fn test_registry_examples() {
+    let examples: [(u8, &str, &[&str]); 5] = [
+        (
+            1,
+            include_str!("../../day01/input-example"),
+            &["24000", "45000"],
+        ),
+        (2, include_str!("../../day02/input-example"), &["15", "12"]),
+        (
+            5,
+            include_str!("../../day05/input-example"),
+            &["CMZ", "MCD"],
+        ),
+        (
+            13,
+            include_str!("../../day13/input-example"),
+            &["13", "140"],
+        ),
+        (25, include_str!("../../day25/input-example"), &["2=-1=0"]),
+    ];
+    for (day, input, answers) in examples {
+        let result = solutions::solve(solutions::get(2022, day).unwrap(), input).unwrap();
+        assert_eq!(result.answers, answers, "day{:02}", day);
+    }
+}
+
+#[test]
+fn test_day11_monkeys() {
+    let monkeys = day11::parse_input(include_str!("../../day11/input-example"));
+    assert_eq!(monkeys[0].items, VecDeque::from([79, 98]));
+    assert!(matches!(monkeys[0].operation, day11::Operation::Times(19)));
+    assert_eq!(monkeys[0].test, 23);
+    assert_eq!((monkeys[0].test_true, monkeys[0].test_false), (2, 3));
+}
+
+#[test]
+fn test_day13_packets() {
+    let pairs = day13::parse_input(include_str!("../../day13/input-example"));
+    assert!(pairs[0].0 < pairs[0].1);
+    assert!(pairs[2].0 > pairs[2].1);
+    assert!(day13::read_packet("[[1],[2,3,4]]") < day13::read_packet("[[1],4]"));
+}
+
+#[test]
+fn test_day14_cave_map() {
+    let mut cm = day14::CaveMap::parse_input(include_str!("../../day14/input-example"), false);
+    assert_eq!((cm.min_x, cm.max_x, cm.max_y), (494, 503, 9));
+    assert!(cm.drop_sand());
+    assert_eq!(cm.blocked[8][500], day14::Content::Sand);
+}
+
+#[test]
+fn test_day16_valves() {
+    let valves = day16::parse_input(include_str!("../../day16/input-example"));
+    let distances = day16::shortest_paths(&valves);
+    let aa = valves.iter().position(|v| v.name == "AA").unwrap();
+    let hh = valves.iter().position(|v| v.name == "HH").unwrap();
+    assert_eq!(valves[hh].flow_rate, 22);
+    assert_eq!(distances[aa][hh], 5);
+}
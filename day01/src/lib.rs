@@ -0,0 +1,328 @@
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::collections::BinaryHeap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+use core::mem;
+
+pub mod plan;
+pub mod stats;
+
+pub fn run(input: &str) -> (u64, u128) {
+    answers(parse_input(input)).unwrap_or_else(|e| panic!("{}", e))
+}
+
+// Both answers for elves already parsed, e.g. by parse_reader.
+pub fn answers(elfs: Vec<Vec<u64>>) -> Result<(u64, u128), String> {
+    let top = top(elfs, 3)?;
+    let part1_answer = top.first().map_or(0, |elf| elf.total);
+    let part2_answer = top.iter().map(|elf| elf.total as u128).sum();
+    Ok((part1_answer, part2_answer))
+}
+
+pub fn part1(input: &str) -> u64 {
+    let elfs = parse_input(input);
+    elfs.iter().map(|elf| elf.iter().sum()).max().unwrap_or(0)
+}
+
+// The totals fit in u64 each, but three of them together might not.
+pub fn part2(input: &str) -> u128 {
+    top_elves(input, 3)
+        .iter()
+        .map(|elf| elf.total as u128)
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    // position in the input, counting from 0
+    pub index: usize,
+    pub total: u64,
+    pub items: Vec<u64>,
+}
+
+// The `k` elves carrying the most Calories, most first; of elves carrying the same the one that
+// comes first wins. Only the best `k` so far are kept, in a heap with the worst of them on top.
+pub fn top_elves(input: &str, k: usize) -> Vec<Elf> {
+    top(parse_input(input), k).unwrap_or_else(|e| panic!("{}", e))
+}
+
+// Like top_elves for elves already parsed, failing like the parser does if an elf's total does
+// not fit in u64.
+pub fn top(mut elfs: Vec<Vec<u64>>, k: usize) -> Result<Vec<Elf>, String> {
+    let capacity = k.min(elfs.len()).saturating_add(1);
+    let mut heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>> = BinaryHeap::with_capacity(capacity);
+    for (index, elf) in elfs.iter().enumerate() {
+        let total = elf
+            .iter()
+            .try_fold(0u64, |total, &cal| total.checked_add(cal))
+            .ok_or_else(|| format!("elf {}: total overflows u64", index + 1))?;
+        heap.push(Reverse((total, Reverse(index))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| Elf {
+            index,
+            total,
+            items: mem::take(&mut elfs[index]),
+        })
+        .collect())
+}
+
+// Narrates part 1 elf by elf, for at most `steps` elves.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut most = 0;
+    parse_input(input)
+        .iter()
+        .take(steps)
+        .enumerate()
+        .map(|(i, elf)| {
+            let total: u64 = elf.iter().sum();
+            most = most.max(total);
+            let items: Vec<String> = elf.iter().map(|cal| cal.to_string()).collect();
+            format!(
+                "elf {} carries {} = {} Calories, the most so far is {}",
+                i + 1,
+                items.join(" + "),
+                total,
+                most
+            )
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<u64>> {
+    try_parse_input(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_parse_input(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    let mut parser = Parser::new();
+    for line in input.lines() {
+        parser.push_line(line)?;
+    }
+    Ok(parser.finish())
+}
+
+// Parses the input as it is read, without holding all of it in memory.
+#[cfg(feature = "std")]
+pub fn parse_reader<R: std::io::BufRead>(mut reader: R) -> Result<Vec<Vec<u64>>, ParseError> {
+    let mut parser = Parser::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => return Ok(parser.finish()),
+            Ok(_) => parser.push_line(&line)?,
+            Err(e) => {
+                return Err(ParseError {
+                    line: parser.line + 1,
+                    message: format!("cannot read: {}", e),
+                })
+            }
+        }
+    }
+}
+
+// A problem with the input, on a line counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+// Collects the elves a line at a time. Elves are separated by any number of blank lines, lines
+// may end in CRLF and the Calories are summed up as u64, failing rather than overflowing.
+#[derive(Debug, Default)]
+pub struct Parser {
+    elfs: Vec<Vec<u64>>,
+    items: Vec<u64>,
+    total: u64,
+    line: usize,
+}
+
+impl Parser {
+    pub fn new() -> Parser {
+        Parser::default()
+    }
+
+    pub fn push_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.line += 1;
+        let line = line.trim();
+        if line.is_empty() {
+            if !self.items.is_empty() {
+                self.elfs.push(mem::take(&mut self.items));
+                self.total = 0;
+            }
+            return Ok(());
+        }
+        let calories = line.parse::<u64>().map_err(|_| {
+            let message = if line.chars().all(|c| c.is_ascii_digit()) {
+                format!("Calories {} are out of range", line)
+            } else {
+                format!("Calories {:?} are not a number", line)
+            };
+            self.error(message)
+        })?;
+        self.total = self
+            .total
+            .checked_add(calories)
+            .ok_or_else(|| self.error("elf total overflows u64".to_string()))?;
+        self.items.push(calories);
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Vec<Vec<u64>> {
+        if !self.items.is_empty() {
+            self.elfs.push(self.items);
+        }
+        self.elfs
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError {
+            line: self.line,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_example_parse() {
+        let elfs = parse_input(include_str!("../input-example"));
+        assert_eq!(elfs.len(), 5);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 24000);
+        assert_eq!(part2_answer, 45000);
+    }
+
+    #[test]
+    fn test_tolerant_parse() {
+        let elfs = parse_input("\n1000\r\n2000\r\n\r\n\n \n3000 \n\n");
+        assert_eq!(elfs, [vec![1000, 2000], vec![3000]]);
+        assert_eq!(parse_input(""), Vec::<Vec<u64>>::new());
+        let max = u64::MAX.to_string();
+        assert_eq!(part1(&format!("{}\n\n{}\n0\n", max, max)), u64::MAX);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| try_parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            error("1\n\nx1\n"),
+            "line 3: Calories \"x1\" are not a number"
+        );
+        assert_eq!(
+            error("1\n99999999999999999999\n"),
+            "line 2: Calories 99999999999999999999 are out of range"
+        );
+        let max = u64::MAX.to_string();
+        assert_eq!(
+            error(&format!("{}\n\n{}\n1\n", max, max)),
+            "line 4: elf total overflows u64"
+        );
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let input = include_str!("../input-example");
+        assert_eq!(
+            parse_reader(input.replace('\n', "\r\n").as_bytes()),
+            Ok(parse_input(input))
+        );
+        let elfs = parse_reader(input.as_bytes()).unwrap();
+        assert_eq!(answers(elfs), Ok((24000, 45000)));
+        assert_eq!(answers(Vec::new()), Ok((0, 0)));
+        let error = parse_reader(&b"1\n\xff\n"[..]).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.message.starts_with("cannot read: "));
+    }
+
+    #[test]
+    fn test_part2_beyond_u64() {
+        let input = "18446744073709551615\n\n18446744073709551615\n\n1\n";
+        assert_eq!(part1(input), u64::MAX);
+        assert_eq!(part2(input), 2 * u64::MAX as u128 + 1);
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let best = top_elves(include_str!("../input-example"), 2);
+        assert_eq!(
+            best,
+            [
+                Elf {
+                    index: 3,
+                    total: 24000,
+                    items: vec![7000, 8000, 9000],
+                },
+                Elf {
+                    index: 2,
+                    total: 11000,
+                    items: vec![5000, 6000],
+                },
+            ]
+        );
+        assert_eq!(top_elves(include_str!("../input-example"), 9).len(), 5);
+        assert_eq!(top_elves(include_str!("../input-example"), 0), []);
+        assert_eq!(
+            top_elves(include_str!("../input-example"), usize::MAX).len(),
+            5
+        );
+
+        let tied: Vec<usize> = top_elves("1\n\n2\n\n2\n\n2\n", 2)
+            .iter()
+            .map(|elf| elf.index)
+            .collect();
+        assert_eq!(tied, [1, 2]);
+
+        // unlike the parser's, these totals have not been checked yet
+        let error = "elf 2: total overflows u64".to_string();
+        assert_eq!(top(vec![vec![1], vec![u64::MAX, 1]], 1), Err(error.clone()));
+        assert_eq!(answers(vec![vec![1], vec![u64::MAX, 1]]), Err(error));
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 2);
+        assert_eq!(
+            steps,
+            [
+                "elf 1 carries 1000 + 2000 + 3000 = 6000 Calories, the most so far is 6000",
+                "elf 2 carries 4000 = 4000 Calories, the most so far is 6000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 67016);
+        assert_eq!(part2_answer, 200116);
+    }
+}
@@ -1,57 +1,88 @@
-use std::cmp::Reverse;
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::process;
+
+const USAGE: &str = "usage: day01 [--top <k> | --stats | --plan <n>] [<input>]
+
+With --top the <k> elves carrying the most Calories are listed instead of the
+answers, with --stats statistics of the Calories the elves carry and with --plan
+a way of sharing all the items out among <n> elves so that the most any of them
+carries is as small as possible.";
 
 fn main() {
-    let (part1_answer, part2_answer) = run(include_str!("../input"));
+    let mut path = None;
+    let mut top = None;
+    let mut stats = false;
+    let mut plan = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => {
+                top = args.next().and_then(|k| k.parse::<usize>().ok());
+                if top.is_none() {
+                    usage_error("--top expects a number");
+                }
+            }
+            "--stats" => stats = true,
+            "--plan" => {
+                plan = args.next().and_then(|n| n.parse::<usize>().ok());
+                if plan.is_none_or(|n| n == 0) {
+                    usage_error("--plan expects a number of elves");
+                }
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => path = Some(arg),
+        }
+    }
+    // a file is parsed as it is read
+    let elfs = match &path {
+        Some(path) => File::open(path)
+            .map_err(|e| e.to_string())
+            .and_then(|file| day01::parse_reader(BufReader::new(file)).map_err(|e| e.to_string())),
+        None => day01::try_parse_input(include_str!("../input")).map_err(|e| e.to_string()),
+    };
+    let elfs = elfs.unwrap_or_else(|e| {
+        eprintln!("{}: {}", path.as_deref().unwrap_or("input"), e);
+        process::exit(1);
+    });
+    if let Some(n) = plan {
+        let report = day01::plan::report_for(&elfs, n).unwrap_or_else(|e| fail(&e));
+        print!("{}", report);
+        return;
+    }
+    if stats {
+        print!("{}", day01::stats::report_for(&elfs));
+        return;
+    }
+    if let Some(k) = top {
+        let top = day01::top(elfs, k).unwrap_or_else(|e| fail(&e));
+        for (rank, elf) in top.iter().enumerate() {
+            let items: Vec<String> = elf.items.iter().map(|cal| cal.to_string()).collect();
+            println!(
+                "{}. elf {} carries {} Calories: {}",
+                rank + 1,
+                elf.index + 1,
+                elf.total,
+                items.join(" + ")
+            );
+        }
+        return;
+    }
+    let (part1_answer, part2_answer) = day01::answers(elfs).unwrap_or_else(|e| fail(&e));
     println!("part 1 answer: {}", part1_answer);
     println!("part 2 answer: {}", part2_answer);
 }
 
-fn run(input: &'static str) -> (u32, u32) {
-    let elfs = parse_input(input);
-
-    let mut cals: Vec<u32> = elfs.iter().map(|elf| elf.iter().sum()).collect();
-
-    let top_elf = *cals.iter().max().unwrap();
-
-    cals.sort_by_key(|w| Reverse(*w));
-    let top3_elfs = cals.iter().take(3).sum();
-
-    (top_elf, top3_elfs)
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
 
-fn parse_input(input: &'static str) -> Vec<Vec<u32>> {
-    let mut ret = Vec::new();
-    for elf in input.trim_end().split("\n\n") {
-        let cal = elf
-            .lines()
-            .map(|line_str| line_str.parse::<u32>().unwrap())
-            .collect();
-        ret.push(cal);
-    }
-    ret
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example_parse() {
-        let elfs = parse_input(include_str!("../input-example"));
-        assert_eq!(elfs.len(), 5);
-    }
-
-    #[test]
-    fn test_example_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
-        assert_eq!(part1_answer, 24000);
-        assert_eq!(part2_answer, 45000);
-    }
-
-    #[test]
-    fn test_input_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input"));
-        assert_eq!(part1_answer, 67016);
-        assert_eq!(part2_answer, 200116);
-    }
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
 }
@@ -0,0 +1,289 @@
+// Rebalancing the snacks: all the items are shared out among a number of elves so that the most
+// any of them carries is as small as possible. That is multiway number partitioning, which is
+// NP-hard, so the greedy longest-processing-time plan is used as is for larger inputs and only
+// improved on by an exact branch and bound search for small ones.
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::Write;
+
+use crate::parse_input;
+
+// The most items the exact search is tried for.
+pub const EXACT_LIMIT: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    // the items each elf carries, heaviest first; there are no more elves than items, as any
+    // more would carry nothing
+    pub elves: Vec<Vec<u64>>,
+    // whether no plan carries less at most, rather than this being the greedy plan
+    pub optimal: bool,
+}
+
+impl Plan {
+    pub fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(|items| items.iter().sum()).collect()
+    }
+
+    pub fn max(&self) -> u64 {
+        self.totals().into_iter().max().unwrap_or(0)
+    }
+
+    // the difference between the most and the least carried
+    pub fn spread(&self) -> u64 {
+        spread(&self.totals())
+    }
+}
+
+// Shares `items` out among `elves` elves, failing if there are none or the items add up to more
+// than a u64 holds.
+pub fn plan(items: &[u64], elves: usize) -> Result<Plan, String> {
+    if elves == 0 {
+        return Err("there must be at least one elf".to_string());
+    }
+    // no elf ever carries more than all the items, so once their total fits none of the loads
+    // below can overflow
+    let total = items
+        .iter()
+        .try_fold(0u64, |total, &item| total.checked_add(item))
+        .ok_or_else(|| "the items add up to more than fits in a u64".to_string())?;
+    let elves = elves.min(items.len()).max(1);
+    let mut items = items.to_vec();
+    items.sort_by_key(|&item| Reverse(item));
+
+    let greedy = greedy(&items, elves);
+    if items.len() > EXACT_LIMIT {
+        return Ok(to_plan(&items, &greedy, elves, false));
+    }
+
+    let lower_bound = items
+        .first()
+        .copied()
+        .unwrap_or(0)
+        .max(total.div_ceil(elves as u64));
+    let mut search = Search {
+        items: &items,
+        loads: vec![0; elves],
+        assignment: vec![0; items.len()],
+        best: greedy.clone(),
+        best_max: max_load(&items, &greedy, elves),
+        lower_bound,
+    };
+    search.branch(0);
+    Ok(to_plan(&items, &search.best, elves, true))
+}
+
+// Gives each item, heaviest first, to the elf carrying the least so far.
+fn greedy(items: &[u64], elves: usize) -> Vec<usize> {
+    let mut loads = vec![0u64; elves];
+    items
+        .iter()
+        .map(|&item| {
+            let elf = (0..elves).min_by_key(|&elf| loads[elf]).unwrap();
+            loads[elf] += item;
+            elf
+        })
+        .collect()
+}
+
+struct Search<'a> {
+    // heaviest first, so that bad branches are cut early
+    items: &'a [u64],
+    loads: Vec<u64>,
+    // the elf each item goes to
+    assignment: Vec<usize>,
+    best: Vec<usize>,
+    best_max: u64,
+    // no plan can do better than the heaviest item or an even share
+    lower_bound: u64,
+}
+
+impl Search<'_> {
+    fn branch(&mut self, i: usize) {
+        if self.best_max == self.lower_bound {
+            return;
+        }
+        if i == self.items.len() {
+            self.best_max = self.loads.iter().copied().max().unwrap();
+            self.best.clone_from(&self.assignment);
+            return;
+        }
+        let item = self.items[i];
+        for elf in 0..self.loads.len() {
+            // elves carrying the same are interchangeable
+            if self.loads[..elf].contains(&self.loads[elf]) {
+                continue;
+            }
+            if self.loads[elf] + item >= self.best_max {
+                continue;
+            }
+            self.loads[elf] += item;
+            self.assignment[i] = elf;
+            self.branch(i + 1);
+            self.loads[elf] -= item;
+        }
+    }
+}
+
+fn max_load(items: &[u64], assignment: &[usize], elves: usize) -> u64 {
+    let mut loads = vec![0u64; elves];
+    for (item, &elf) in items.iter().zip(assignment) {
+        loads[elf] += item;
+    }
+    loads.into_iter().max().unwrap_or(0)
+}
+
+fn to_plan(items: &[u64], assignment: &[usize], elves: usize, optimal: bool) -> Plan {
+    let mut plan = Plan {
+        elves: vec![Vec::new(); elves],
+        optimal,
+    };
+    for (&item, &elf) in items.iter().zip(assignment) {
+        plan.elves[elf].push(item);
+    }
+    plan
+}
+
+fn spread(totals: &[u64]) -> u64 {
+    let max = totals.iter().copied().max().unwrap_or(0);
+    let min = totals.iter().copied().min().unwrap_or(0);
+    max - min
+}
+
+// Plans sharing out everything the elves in the input carry among `elves` elves, and describes
+// how the spread between the most and least carried changes.
+pub fn report(input: &str, elves: usize) -> Result<String, String> {
+    report_for(&parse_input(input), elves)
+}
+
+pub fn report_for(elfs: &[Vec<u64>], elves: usize) -> Result<String, String> {
+    let before: Vec<u64> = elfs.iter().map(|elf| elf.iter().sum()).collect();
+    let items: Vec<u64> = elfs.concat();
+    let plan = plan(&items, elves)?;
+    let mut after = plan.totals();
+    // the elves left over carry nothing
+    let idle = elves - plan.elves.len();
+    if idle > 0 {
+        after.push(0);
+    }
+
+    let mut s = String::new();
+    for (label, count, totals) in [("before", elfs.len(), &before), ("after", elves, &after)] {
+        let _ = writeln!(
+            s,
+            "{}: {} elves carry {} to {} Calories, a spread of {}",
+            label,
+            count,
+            totals.iter().min().unwrap_or(&0),
+            totals.iter().max().unwrap_or(&0),
+            spread(totals)
+        );
+    }
+    let how = if plan.optimal {
+        "optimal"
+    } else {
+        "greedy, heaviest items first"
+    };
+    let _ = writeln!(s, "plan ({}):", how);
+    for (i, (items, total)) in plan.elves.iter().zip(&after).enumerate() {
+        let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+        let _ = writeln!(s, "elf {}: {} = {}", i + 1, items.join(" + "), total);
+    }
+    match idle {
+        0 => {}
+        1 => {
+            let _ = writeln!(s, "elf {}: nothing", elves);
+        }
+        _ => {
+            let _ = writeln!(s, "elves {} to {}: nothing", plan.elves.len() + 1, elves);
+        }
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_beats_greedy() {
+        // greedy ends up with 3 + 2 + 2 for one elf
+        let items = [2, 3, 2, 3, 2];
+        assert_eq!(
+            max_load(&[3, 3, 2, 2, 2], &greedy(&[3, 3, 2, 2, 2], 2), 2),
+            7
+        );
+        let plan = plan(&items, 2).unwrap();
+        assert!(plan.optimal);
+        assert_eq!(plan.max(), 6);
+        assert_eq!(plan.spread(), 0);
+        let mut carried: Vec<u64> = plan.elves.concat();
+        carried.sort();
+        assert_eq!(carried, [2, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_example_plan() {
+        let items: Vec<u64> = parse_input(include_str!("../input-example"))
+            .into_iter()
+            .flatten()
+            .collect();
+        let five = plan(&items, 5).unwrap();
+        assert!(five.optimal);
+        assert_eq!(five.max(), 11000);
+        assert_eq!(plan(&items, 1).unwrap().totals(), [55000]);
+        assert_eq!(plan(&items, 12).unwrap().max(), 10000);
+    }
+
+    #[test]
+    fn test_more_elves_than_items() {
+        let two = plan(&[3, 5], usize::MAX).unwrap();
+        assert_eq!(two.elves, [vec![5], vec![3]]);
+        assert_eq!(plan(&[], 4).unwrap().elves, [Vec::<u64>::new()]);
+        assert_eq!(
+            report_for(&[vec![3], vec![5]], 4).unwrap(),
+            "before: 2 elves carry 3 to 5 Calories, a spread of 2\n\
+             after: 4 elves carry 0 to 5 Calories, a spread of 5\n\
+             plan (optimal):\n\
+             elf 1: 5 = 5\n\
+             elf 2: 3 = 3\n\
+             elves 3 to 4: nothing\n"
+        );
+    }
+
+    #[test]
+    fn test_no_elves() {
+        let error = "there must be at least one elf".to_string();
+        assert_eq!(plan(&[1, 2], 0), Err(error.clone()));
+        assert_eq!(report("1\n\n2\n", 0), Err(error));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // each elf's total fits in a u64, but not all of them together
+        let elfs = [vec![u64::MAX], vec![1]];
+        let error = "the items add up to more than fits in a u64".to_string();
+        assert_eq!(plan(&elfs.concat(), 2), Err(error.clone()));
+        assert_eq!(report_for(&elfs, 2), Err(error));
+    }
+
+    #[test]
+    fn test_greedy_for_many_items() {
+        let items: Vec<u64> = (1..=40).collect();
+        let plan = plan(&items, 4).unwrap();
+        assert!(!plan.optimal);
+        assert_eq!(plan.totals().iter().sum::<u64>(), 820);
+        assert_eq!(plan.max(), 205);
+    }
+
+    #[test]
+    fn test_report() {
+        snapshot::assert_snapshot!(
+            "example-plan",
+            report(include_str!("../input-example"), 3).unwrap()
+        );
+    }
+}
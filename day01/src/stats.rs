@@ -0,0 +1,238 @@
+// Statistics over the Calories the elves carry, for planning supply runs.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::parse_input;
+
+// The percentiles reported besides the median.
+pub const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+const BINS: u64 = 10;
+const BAR_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    // the totals at PERCENTILES, by the nearest-rank method
+    pub percentiles: [u64; PERCENTILES.len()],
+    // of the population
+    pub std_dev: f64,
+    // (index, number of items) of the elves carrying the most and the fewest items; the first
+    // such elf if there are several
+    pub most_items: (usize, usize),
+    pub fewest_items: (usize, usize),
+    // (lowest total, highest total, elves) of bins of equal width from min to max, but for the last
+    // one, which ends at max
+    pub histogram: Vec<(u64, u64, usize)>,
+}
+
+// The statistics, or None if there are no elves.
+pub fn stats(input: &str) -> Option<Stats> {
+    summarise(&parse_input(input))
+}
+
+pub fn summarise(elfs: &[Vec<u64>]) -> Option<Stats> {
+    let totals: Vec<u64> = elfs.iter().map(|elf| elf.iter().sum()).collect();
+    let mut sorted = totals.clone();
+    sorted.sort();
+    let count = sorted.len();
+    let (&min, &max) = (sorted.first()?, sorted.last()?);
+
+    let mean = sorted.iter().map(|&t| t as f64).sum::<f64>() / count as f64;
+    let median = if count % 2 == 1 {
+        sorted[count / 2] as f64
+    } else {
+        (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0
+    };
+    let percentiles = PERCENTILES.map(|p| {
+        let rank = (p as usize * count).div_ceil(100).max(1);
+        sorted[rank - 1]
+    });
+    let variance = sorted
+        .iter()
+        .map(|&t| (t as f64 - mean) * (t as f64 - mean))
+        .sum::<f64>()
+        / count as f64;
+
+    let items = |(i, elf): (usize, &Vec<u64>)| (i, elf.len());
+    let most_items = elfs.iter().enumerate().map(items).rev().max_by_key(|e| e.1);
+    let fewest_items = elfs.iter().enumerate().map(items).min_by_key(|e| e.1);
+
+    // in u128, since neither the number of totals from min to max nor the ends of the bins need
+    // fit in u64
+    let range = (max - min) as u128 + 1;
+    let width = (range - 1) / (BINS as u128).min(range) + 1;
+    // rounding the width up can leave fewer bins needed, and none may start above max
+    let bins = range.div_ceil(width);
+    let mut histogram: Vec<(u64, u64, usize)> = (0..bins)
+        .map(|i| {
+            let low = min as u128 + i * width;
+            let high = (low + width - 1).min(max as u128);
+            (low as u64, high as u64, 0)
+        })
+        .collect();
+    for &total in &totals {
+        histogram[((total - min) as u128 / width) as usize].2 += 1;
+    }
+
+    Some(Stats {
+        count,
+        min,
+        max,
+        mean,
+        median,
+        percentiles,
+        std_dev: sqrt(variance),
+        most_items: most_items?,
+        fewest_items: fewest_items?,
+        histogram,
+    })
+}
+
+// The statistics as text, with elves numbered from 1 as in the puzzle.
+pub fn report(input: &str) -> String {
+    report_for(&parse_input(input))
+}
+
+pub fn report_for(elfs: &[Vec<u64>]) -> String {
+    let mut s = String::new();
+    let Some(stats) = summarise(elfs) else {
+        let _ = writeln!(s, "elves: 0");
+        return s;
+    };
+    let _ = writeln!(s, "elves: {}", stats.count);
+    let _ = writeln!(
+        s,
+        "Calories per elf: min {}, max {}, mean {:.1}, median {:.1}, standard deviation {:.1}",
+        stats.min, stats.max, stats.mean, stats.median, stats.std_dev
+    );
+    let percentiles: Vec<String> = PERCENTILES
+        .iter()
+        .zip(stats.percentiles)
+        .map(|(p, total)| format!("{}th {}", p, total))
+        .collect();
+    let _ = writeln!(s, "percentiles: {}", percentiles.join(", "));
+    for (label, (index, items)) in [("most", stats.most_items), ("fewest", stats.fewest_items)] {
+        let plural = if items == 1 { "" } else { "s" };
+        let _ = writeln!(
+            s,
+            "{} items: elf {} with {} item{}",
+            label,
+            index + 1,
+            items,
+            plural
+        );
+    }
+
+    let _ = writeln!(s, "histogram:");
+    let digits = format!("{}", stats.max).len();
+    let most = stats
+        .histogram
+        .iter()
+        .map(|bin| bin.2)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for (low, high, elves) in &stats.histogram {
+        let mut bar = "#".repeat(elves * BAR_WIDTH / most);
+        if !bar.is_empty() {
+            bar.push(' ');
+        }
+        let _ = writeln!(s, "{:>w$}-{:<w$} | {}{}", low, high, bar, elves, w = digits);
+    }
+    s
+}
+
+// core has no f64::sqrt; Newton's method from above converges in a few dozen steps
+fn sqrt(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let mut r = x.max(1.0);
+    loop {
+        let next = (r + x / r) / 2.0;
+        if next >= r {
+            return r;
+        }
+        r = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_stats() {
+        let stats = stats(include_str!("../input-example")).unwrap();
+        assert_eq!(stats.count, 5);
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(stats.percentiles, [4000, 6000, 11000, 24000]);
+        assert!((stats.std_dev - 6985.7).abs() < 0.1);
+        assert_eq!(stats.most_items, (0, 3));
+        assert_eq!(stats.fewest_items, (1, 1));
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(stats.histogram[0], (4000, 6000, 2));
+        assert_eq!(stats.histogram[9], (22009, 24000, 1));
+    }
+
+    #[test]
+    fn test_no_elves() {
+        assert_eq!(stats(""), None);
+        assert_eq!(report(""), "elves: 0\n");
+    }
+
+    #[test]
+    fn test_histogram_ends_at_max() {
+        let odd = stats("1\n\n3\n\n5\n").unwrap();
+        assert_eq!(
+            odd.histogram,
+            [(1, 1, 1), (2, 2, 0), (3, 3, 1), (4, 4, 0), (5, 5, 1)]
+        );
+        let one = stats("7\n").unwrap();
+        assert_eq!(one.histogram, [(7, 7, 1)]);
+        let uneven = stats("0\n\n10\n").unwrap();
+        assert_eq!(
+            uneven.histogram,
+            [
+                (0, 1, 1),
+                (2, 3, 0),
+                (4, 5, 0),
+                (6, 7, 0),
+                (8, 9, 0),
+                (10, 10, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extreme_totals() {
+        let extreme = stats("0\n\n18446744073709551615\n").unwrap();
+        assert_eq!((extreme.min, extreme.max), (0, u64::MAX));
+        assert_eq!(extreme.histogram.len(), 10);
+        assert_eq!(extreme.histogram[0], (0, 1844674407370955161, 1));
+        assert_eq!(extreme.histogram[9], (16602069666338596458, u64::MAX, 1));
+        assert!(report("0\n\n18446744073709551615\n").contains("| #"));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(sqrt(0.0), 0.0);
+        assert_eq!(sqrt(49.0), 7.0);
+        assert!((sqrt(0.25) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_report() {
+        snapshot::assert_snapshot!("example-report", report(include_str!("../input-example")));
+        snapshot::assert_snapshot!("input-report", report(include_str!("../input")));
+    }
+}
@@ -0,0 +1,217 @@
+// Analysis of the opponent's column of the strategy guide: how often they play each shape, which
+// sequences of shapes they repeat, and the best possible responses, both knowing every shape they
+// will play and predicting each one from the rounds before it.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::Write;
+
+use crate::{rounds, AsOutcome, AsShape, Decoder, RuleSet, Shape};
+
+// The lengths of the sequences of their shapes counted as patterns.
+pub const PATTERN_LENGTHS: [usize; 2] = [2, 3];
+// How many of the most common patterns of each length are kept.
+const TOP_PATTERNS: usize = 3;
+// How many of their previous shapes a prediction looks at.
+pub const PREDICTOR_ORDER: usize = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    // how often they play each shape
+    pub frequencies: Vec<usize>,
+    // the most common sequences of their shapes and how often they occur, for each of
+    // PATTERN_LENGTHS
+    pub patterns: Vec<Vec<(Vec<Shape>, usize)>>,
+    // the best shape to play each round, knowing theirs
+    pub optimal: Vec<Shape>,
+    pub optimal_score: u32,
+    // the shapes played when predicting theirs from the rounds before
+    pub predicted: Vec<Shape>,
+    pub predicted_score: u32,
+}
+
+pub fn analyse(input: &str, rules: &RuleSet) -> Analysis {
+    let theirs: Vec<Shape> = rounds(input, rules, &AsShape)
+        .into_iter()
+        .map(|(theirs, _)| theirs)
+        .collect();
+
+    let mut frequencies = vec![0; rules.shapes().count()];
+    for shape in &theirs {
+        frequencies[shape.0] += 1;
+    }
+
+    let patterns = PATTERN_LENGTHS
+        .iter()
+        .map(|&n| {
+            let mut counts: BTreeMap<&[Shape], usize> = BTreeMap::new();
+            for pattern in theirs.windows(n) {
+                *counts.entry(pattern).or_insert(0) += 1;
+            }
+            let mut counts: Vec<(Vec<Shape>, usize)> = counts
+                .into_iter()
+                .map(|(pattern, count)| (pattern.to_vec(), count))
+                .collect();
+            // most common first, then in the order of the shapes
+            counts.sort_by_key(|&(_, count)| Reverse(count));
+            counts.truncate(TOP_PATTERNS);
+            counts
+        })
+        .collect();
+
+    let optimal: Vec<Shape> = theirs.iter().map(|&t| best_response(rules, t)).collect();
+    let predicted: Vec<Shape> = (0..theirs.len())
+        .map(|i| best_response(rules, predict(rules, &theirs[..i])))
+        .collect();
+    let total = |yours: &[Shape]| -> u32 {
+        yours
+            .iter()
+            .zip(&theirs)
+            .map(|(&y, &t)| rules.score(y, t))
+            .sum()
+    };
+
+    Analysis {
+        frequencies,
+        patterns,
+        optimal_score: total(&optimal),
+        optimal,
+        predicted_score: total(&predicted),
+        predicted,
+    }
+}
+
+// The highest scoring shape to play against `theirs`.
+pub fn best_response(rules: &RuleSet, theirs: Shape) -> Shape {
+    let mut best = Shape(0);
+    for shape in rules.shapes() {
+        if rules.score(shape, theirs) > rules.score(best, theirs) {
+            best = shape;
+        }
+    }
+    best
+}
+
+// Predicts their next shape as the one that most often followed their last PREDICTOR_ORDER
+// shapes, or fewer if those never occurred before, down to the one they played most overall.
+// Ties go to the lowest scoring shape, as does the first round.
+pub fn predict(rules: &RuleSet, history: &[Shape]) -> Shape {
+    for order in (0..=PREDICTOR_ORDER.min(history.len())).rev() {
+        let context = &history[history.len() - order..];
+        let mut followers = vec![0; rules.shapes().count()];
+        for window in history.windows(order + 1) {
+            if &window[..order] == context {
+                followers[window[order].0] += 1;
+            }
+        }
+        let mut best = 0;
+        for shape in 1..followers.len() {
+            if followers[shape] > followers[best] {
+                best = shape;
+            }
+        }
+        if followers[best] > 0 {
+            return Shape(best);
+        }
+    }
+    Shape(0)
+}
+
+// The analysis as text, with how far the strategy guide read both ways is from the optimum.
+pub fn report(input: &str, rules: &RuleSet) -> String {
+    let analysis = analyse(input, rules);
+    let rounds_played = analysis.optimal.len();
+    let mut s = String::new();
+    let _ = writeln!(s, "rounds: {}", rounds_played);
+    let _ = writeln!(s, "their shapes:");
+    for shape in rules.shapes() {
+        let _ = writeln!(
+            s,
+            "  {}: {}",
+            rules.name(shape),
+            analysis.frequencies[shape.0]
+        );
+    }
+    for (n, patterns) in PATTERN_LENGTHS.iter().zip(&analysis.patterns) {
+        let _ = writeln!(s, "their most common sequences of {}:", n);
+        for (pattern, count) in patterns {
+            let names: Vec<&str> = pattern.iter().map(|&shape| rules.name(shape)).collect();
+            let _ = writeln!(s, "  {}: {}", names.join(", "), count);
+        }
+    }
+    let _ = writeln!(
+        s,
+        "best possible score, knowing their shapes: {}",
+        analysis.optimal_score
+    );
+    let _ = writeln!(
+        s,
+        "score predicting their shapes from the rounds before: {}",
+        analysis.predicted_score
+    );
+    let readings: [(&str, &dyn Decoder); 2] = [("shapes", &AsShape), ("outcomes", &AsOutcome)];
+    for (reading, decoder) in readings {
+        let guide = rounds(input, rules, decoder);
+        let score: u32 = guide.iter().map(|&(t, y)| rules.score(y, t)).sum();
+        let best = guide
+            .iter()
+            .zip(&analysis.optimal)
+            .filter(|&(&(t, y), &o)| rules.score(y, t) == rules.score(o, t))
+            .count();
+        let _ = writeln!(
+            s,
+            "the guide read as {}: {}, {} below the best, with the best shape in {} of {} rounds",
+            reading,
+            score,
+            analysis.optimal_score - score,
+            best,
+            rounds_played
+        );
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_analysis() {
+        let rules = RuleSet::rock_paper_scissors();
+        let analysis = analyse(include_str!("../input-example"), &rules);
+        assert_eq!(analysis.frequencies, [1, 1, 1]);
+        assert_eq!(
+            analysis.patterns[0],
+            [(vec![Shape(0), Shape(1)], 1), (vec![Shape(1), Shape(2)], 1)]
+        );
+        assert_eq!(analysis.optimal, [Shape(1), Shape(2), Shape(0)]);
+        assert_eq!(analysis.optimal_score, 8 + 9 + 7);
+        // Rock is predicted every round: there is no history, then it is the most common
+        assert_eq!(analysis.predicted, [Shape(1), Shape(1), Shape(1)]);
+        assert_eq!(analysis.predicted_score, 8 + 5 + 2);
+    }
+
+    #[test]
+    fn test_predict() {
+        let rules = RuleSet::rock_paper_scissors();
+        let [r, p, s] = [0, 1, 2].map(Shape);
+        assert_eq!(predict(&rules, &[]), r);
+        // after Rock, Paper they played Scissors
+        assert_eq!(predict(&rules, &[r, p, s, s, r, p]), s);
+        // Paper never came after Scissors, Scissors, so fall back to what follows Scissors
+        assert_eq!(predict(&rules, &[s, p, s, s]), p);
+        assert_eq!(predict(&rules, &[p, p]), p);
+    }
+
+    #[test]
+    fn test_report() {
+        let rules = RuleSet::rock_paper_scissors();
+        let example = include_str!("../input-example");
+        snapshot::assert_snapshot!("example-analysis", report(example, &rules));
+        let input = include_str!("../input");
+        snapshot::assert_snapshot!("input-analysis", report(input, &rules));
+    }
+}
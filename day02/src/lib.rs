@@ -0,0 +1,134 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub mod analysis;
+pub mod rules;
+pub mod tournament;
+
+pub use rules::{AsOutcome, AsShape, Decoder, Outcome, RuleSet, Shape};
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    score(input, &RuleSet::rock_paper_scissors(), &AsShape)
+}
+
+pub fn part2(input: &str) -> u32 {
+    score(input, &RuleSet::rock_paper_scissors(), &AsOutcome)
+}
+
+// The total score for following the strategy guide as read by `decoder`.
+pub fn score(input: &str, rules: &RuleSet, decoder: &dyn Decoder) -> u32 {
+    rounds(input, rules, decoder)
+        .iter()
+        .map(|&(theirs, yours)| rules.score(yours, theirs))
+        .sum()
+}
+
+// The rounds of the strategy guide as their shape and yours.
+pub fn rounds(input: &str, rules: &RuleSet, decoder: &dyn Decoder) -> Vec<(Shape, Shape)> {
+    parse_input(input)
+        .into_iter()
+        .map(|line| {
+            let round = match line.chars().collect::<Vec<char>>()[..] {
+                [a, ' ', b] => rules
+                    .their_shape(a)
+                    .and_then(|theirs| Some((theirs, decoder.decode(rules, theirs, b)?))),
+                _ => None,
+            };
+            round.unwrap_or_else(|| panic!("unknown input: {}", line))
+        })
+        .collect()
+}
+
+// Narrates part 1 round by round, for at most `steps` rounds.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let rules = RuleSet::rock_paper_scissors();
+    let mut total = 0;
+    let mut narration = Vec::new();
+    let lines = parse_input(input);
+    let rounds = rounds(input, &rules, &AsShape);
+    for (i, (line, (theirs, yours))) in lines.iter().zip(rounds).take(steps).enumerate() {
+        let outcome = rules.outcome(yours, theirs);
+        let word = match outcome {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        let score = rules.score(yours, theirs);
+        total += score;
+        narration.push(format!(
+            "round {}, {}: they play {}, you play {} and {}, {} + {} = {} points, {} in total",
+            i + 1,
+            line,
+            rules.name(theirs),
+            rules.name(yours),
+            word,
+            rules.shape_score(yours),
+            outcome.score(),
+            score,
+            total
+        ));
+    }
+    narration
+}
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.trim_end().split("\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let lines = parse_input(include_str!("../input-example"));
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 15);
+        assert_eq!(part2_answer, 12);
+    }
+
+    #[test]
+    fn test_lizard_spock_guide() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        // Spock vaporizes Rock, Lizard eats Paper, Rock crushes Scissors
+        let input = "A Z\nB Y\nC V\n";
+        assert_eq!(score(input, &rules, &AsShape), (5 + 6) + (4 + 6) + (1 + 6));
+        // to win against Rock, Paper and Scissors
+        assert_eq!(score("A Z\nB Z\nC Z\n", &rules, &AsOutcome), 5 + 4 + 5 + 18);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 5);
+        assert_eq!(
+            steps,
+            [
+                "round 1, A Y: they play Rock, you play Paper and win, 2 + 6 = 8 points, 8 in total",
+                "round 2, B X: they play Paper, you play Rock and lose, 1 + 0 = 1 points, 9 in total",
+                "round 3, C Z: they play Scissors, you play Scissors and draw, 3 + 3 = 6 points, \
+                 15 in total",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 10624);
+        assert_eq!(part2_answer, 14060);
+    }
+}
@@ -1,69 +1,109 @@
-fn main() {
-    let (part1_answer, part2_answer) = run(include_str!("../input"));
-    println!("part 1 answer: {}", part1_answer);
-    println!("part 2 answer: {}", part2_answer);
-}
+use std::env;
+use std::fs;
+use std::process;
 
-fn run(input: &'static str) -> (u32, u32) {
-    let lines = parse_input(input);
+use day02::tournament::{self, Player, Strategy};
+use day02::{AsOutcome, AsShape, RuleSet};
 
-    let mut part1_answer: u32 = 0;
-    let mut part2_answer: u32 = 0;
-    for line in lines {
-        part1_answer += match line {
-            "A X" => 1 + 3,
-            "B X" => 1,
-            "C X" => 1 + 6,
-            "A Y" => 2 + 6,
-            "B Y" => 2 + 3,
-            "C Y" => 2,
-            "A Z" => 3,
-            "B Z" => 3 + 6,
-            "C Z" => 3 + 3,
-            _ => panic!("unknown input: {}", line),
-        };
-        part2_answer += match line {
-            "A X" => 3,
-            "B X" => 1,
-            "C X" => 2,
-            "A Y" => 1 + 3,
-            "B Y" => 2 + 3,
-            "C Y" => 3 + 3,
-            "A Z" => 2 + 6,
-            "B Z" => 3 + 6,
-            "C Z" => 1 + 6,
-            _ => panic!("unknown input: {}", line),
-        };
-    }
+const USAGE: &str = "usage: day02 [--rules <file>] [--analyse] [<input>]
+       day02 [--rules <file>] --tournament <rounds> [--seed <seed>] [<input>]
 
-    (part1_answer, part2_answer)
-}
+--rules plays by the beat graph in <file> instead of rock paper scissors, a line
+per shape in the order of their scores such as \"Rock beats Scissors, Lizard\".
+Their shapes are written A, B, C, ... and yours with as many letters ending in Z.
 
-fn parse_input(input: &'static str) -> Vec<&'static str> {
-    input.trim_end().split("\n").collect()
-}
+--analyse reports how often they play each shape and in which sequences, the
+best possible score and how far the guide is from it, instead of the answers.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+--tournament plays <rounds> rounds between every pair of the guide (your column
+read as shapes), a random player seeded with <seed> (by default 2022), a
+frequency counter and win-stay, lose-shift, and shows the standings.";
 
-    #[test]
-    fn test_example_parse() {
-        let lines = parse_input(include_str!("../input-example"));
-        assert_eq!(lines.len(), 3);
+fn main() {
+    let mut path = None;
+    let mut rules = None;
+    let mut analyse = false;
+    let mut tournament_rounds = None;
+    let mut seed = 2022;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => {
+                let file = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--rules expects a file"));
+                let text = fs::read_to_string(&file).unwrap_or_else(|e| {
+                    eprintln!("{}: {}", file, e);
+                    process::exit(1);
+                });
+                rules = Some(RuleSet::parse(&text).unwrap_or_else(|e| {
+                    eprintln!("{}: {}", file, e);
+                    process::exit(1);
+                }));
+            }
+            "--analyse" => analyse = true,
+            "--tournament" => {
+                tournament_rounds = args.next().and_then(|n| n.parse::<usize>().ok());
+                if tournament_rounds.is_none() {
+                    usage_error("--tournament expects a number of rounds");
+                }
+            }
+            "--seed" => {
+                seed = args
+                    .next()
+                    .and_then(|n| n.parse::<u64>().ok())
+                    .unwrap_or_else(|| usage_error("--seed expects a number"));
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => path = Some(arg),
+        }
     }
-
-    #[test]
-    fn test_example_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
-        assert_eq!(part1_answer, 15);
-        assert_eq!(part2_answer, 12);
+    let input = match path {
+        Some(path) => fs::read_to_string(path).unwrap(),
+        None => include_str!("../input").to_string(),
+    };
+    if let Some(rounds) = tournament_rounds {
+        let rules = rules.unwrap_or_else(RuleSet::rock_paper_scissors);
+        let guide = day02::rounds(&input, &rules, &AsShape)
+            .into_iter()
+            .map(|(_, yours)| yours)
+            .collect();
+        let players = [
+            ("guide", Strategy::Fixed(guide)),
+            ("random", Strategy::Random { seed }),
+            ("frequency counter", Strategy::FrequencyCounter),
+            ("win-stay, lose-shift", Strategy::WinStayLoseShift),
+        ]
+        .map(|(name, strategy)| Player {
+            name: name.to_string(),
+            strategy,
+        });
+        print!(
+            "{}",
+            tournament::round_robin(&rules, &players, rounds).table()
+        );
+        return;
     }
-
-    #[test]
-    fn test_input_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input"));
-        assert_eq!(part1_answer, 10624);
-        assert_eq!(part2_answer, 14060);
+    if analyse {
+        let rules = rules.unwrap_or_else(RuleSet::rock_paper_scissors);
+        print!("{}", day02::analysis::report(&input, &rules));
+        return;
     }
+    let (part1_answer, part2_answer) = match rules {
+        Some(rules) => (
+            day02::score(&input, &rules, &AsShape),
+            day02::score(&input, &rules, &AsOutcome),
+        ),
+        None => day02::run(&input),
+    };
+    println!("part 1 answer: {}", part1_answer);
+    println!("part 2 answer: {}", part2_answer);
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
 }
@@ -0,0 +1,245 @@
+// Rules of rock paper scissors style games: which shapes there are, what playing each of them
+// scores and which beats which.
+//
+// Shapes score by their position in the rule set, from 1. In the strategy guide their shapes are
+// written as A, B, C, ... and yours with as many letters ending in Z, so X, Y and Z for the three
+// shapes of rock paper scissors and V to Z for the five of rock paper scissors lizard Spock.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+// A shape, by its position in its rule set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    names: Vec<String>,
+    // beats[a][b] is whether shape a beats shape b
+    beats: Vec<Vec<bool>>,
+}
+
+impl RuleSet {
+    pub fn rock_paper_scissors() -> RuleSet {
+        RuleSet::parse(
+            "Rock beats Scissors\n\
+             Paper beats Rock\n\
+             Scissors beats Paper\n",
+        )
+        .unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> RuleSet {
+        RuleSet::parse(
+            "Rock beats Scissors, Lizard\n\
+             Paper beats Rock, Spock\n\
+             Scissors beats Paper, Lizard\n\
+             Lizard beats Paper, Spock\n\
+             Spock beats Rock, Scissors\n",
+        )
+        .unwrap()
+    }
+
+    // Parses a beat graph with a line for each shape, in the order of their scores:
+    //
+    //     Rock beats Scissors, Lizard
+    //
+    // A shape that beats nothing is just its name. Shapes neither of which beats the other draw.
+    pub fn parse(text: &str) -> Result<RuleSet, String> {
+        let lines: Vec<(usize, &str)> = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        let names: Vec<String> = lines
+            .iter()
+            .map(|(_, line)| match line.split_once(" beats ") {
+                Some((name, _)) => name.trim().to_string(),
+                None => line.to_string(),
+            })
+            .collect();
+        if names.is_empty() {
+            return Err("no shapes".to_string());
+        }
+        if names.len() > 13 {
+            return Err(format!("{} shapes, at most 13 fit in A to Z", names.len()));
+        }
+
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (a, (n, line)) in lines.iter().enumerate() {
+            if names[..a].contains(&names[a]) {
+                return Err(format!("line {}: {} is listed twice", n, names[a]));
+            }
+            let Some((_, beaten)) = line.split_once(" beats ") else {
+                continue;
+            };
+            for name in beaten.split(',').map(str::trim) {
+                let Some(b) = names.iter().position(|s| s == name) else {
+                    return Err(format!("line {}: unknown shape {}", n, name));
+                };
+                if a == b {
+                    return Err(format!("line {}: {} cannot beat itself", n, name));
+                }
+                beats[a][b] = true;
+            }
+        }
+        for a in 0..names.len() {
+            for b in 0..a {
+                if beats[a][b] && beats[b][a] {
+                    return Err(format!("{} and {} beat each other", names[b], names[a]));
+                }
+            }
+        }
+        Ok(RuleSet { names, beats })
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn shape_score(&self, shape: Shape) -> u32 {
+        shape.0 as u32 + 1
+    }
+
+    pub fn outcome(&self, yours: Shape, theirs: Shape) -> Outcome {
+        if self.beats[yours.0][theirs.0] {
+            Outcome::Win
+        } else if self.beats[theirs.0][yours.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // the score of a round for you
+    pub fn score(&self, yours: Shape, theirs: Shape) -> u32 {
+        self.shape_score(yours) + self.outcome(yours, theirs).score()
+    }
+
+    // The shape to play against `theirs` for `outcome`, the highest scoring one if there are
+    // several.
+    pub fn respond(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&yours| self.outcome(yours, theirs) == outcome)
+            .last()
+    }
+
+    // their shape in the first column of the strategy guide
+    pub fn their_shape(&self, column: char) -> Option<Shape> {
+        let i = (column as usize).checked_sub('A' as usize)?;
+        (i < self.names.len()).then_some(Shape(i))
+    }
+
+    // your shape in the second column of the strategy guide
+    pub fn your_shape(&self, column: char) -> Option<Shape> {
+        let first = 'Z' as usize + 1 - self.names.len();
+        let i = (column as usize).checked_sub(first)?;
+        (i < self.names.len()).then_some(Shape(i))
+    }
+}
+
+// A reading of the second column of the strategy guide, as the shape you play against theirs.
+pub trait Decoder {
+    fn decode(&self, rules: &RuleSet, theirs: Shape, column: char) -> Option<Shape>;
+}
+
+// Part 1's reading: the second column is the shape you play.
+pub struct AsShape;
+
+impl Decoder for AsShape {
+    fn decode(&self, rules: &RuleSet, _theirs: Shape, column: char) -> Option<Shape> {
+        rules.your_shape(column)
+    }
+}
+
+// Part 2's reading: X, Y and Z mean you need to lose, draw or win.
+pub struct AsOutcome;
+
+impl Decoder for AsOutcome {
+    fn decode(&self, rules: &RuleSet, theirs: Shape, column: char) -> Option<Shape> {
+        let outcome = match column {
+            'X' => Outcome::Lose,
+            'Y' => Outcome::Draw,
+            'Z' => Outcome::Win,
+            _ => return None,
+        };
+        rules.respond(theirs, outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let rules = RuleSet::rock_paper_scissors();
+        let [rock, paper, scissors] = [Shape(0), Shape(1), Shape(2)];
+        assert_eq!(rules.name(scissors), "Scissors");
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.score(paper, rock), 8);
+        assert_eq!(rules.respond(rock, Outcome::Lose), Some(scissors));
+        assert_eq!(rules.their_shape('C'), Some(scissors));
+        assert_eq!(rules.your_shape('X'), Some(rock));
+        assert_eq!(rules.your_shape('W'), None);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let [rock, paper, _, lizard, spock] = [0, 1, 2, 3, 4].map(Shape);
+        assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+        assert_eq!(rules.outcome(spock, lizard), Outcome::Lose);
+        // Paper and Spock both beat Rock
+        assert_eq!(rules.respond(rock, Outcome::Win), Some(spock));
+        assert_eq!(rules.respond(paper, Outcome::Draw), Some(paper));
+        assert_eq!(rules.your_shape('V'), Some(rock));
+        assert_eq!(rules.their_shape('E'), Some(spock));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(RuleSet::parse("# nothing\n"), Err("no shapes".to_string()));
+        assert_eq!(
+            RuleSet::parse("Rock beats Scisors\nScissors\n"),
+            Err("line 1: unknown shape Scisors".to_string())
+        );
+        assert_eq!(
+            RuleSet::parse("Rock beats Paper\nPaper beats Rock\n"),
+            Err("Rock and Paper beat each other".to_string())
+        );
+        assert_eq!(
+            RuleSet::parse("Rock\nRock\n"),
+            Err("line 2: Rock is listed twice".to_string())
+        );
+        // neither beats the other
+        let rules = RuleSet::parse("Stone\nRock\n").unwrap();
+        assert_eq!(rules.outcome(Shape(0), Shape(1)), Outcome::Draw);
+    }
+}
@@ -0,0 +1,299 @@
+// Round-robin tournaments between strategies: every player plays every other one for a number of
+// rounds, scoring each round as in the puzzle. Random players are seeded, so a tournament always
+// plays out the same way.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::Write;
+
+use crate::analysis::best_response;
+use crate::{Outcome, RuleSet, Shape};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    // plays these shapes in order, over and over, such as a strategy guide's
+    Fixed(Vec<Shape>),
+    Random { seed: u64 },
+    // plays the best response to the shape the opponent played most so far, taking them for the
+    // first shape until they have played anything
+    FrequencyCounter,
+    // starts with the first shape, then plays the same shape again after winning and otherwise
+    // the best response to the opponent's last shape
+    WinStayLoseShift,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    pub name: String,
+    pub strategy: Strategy,
+}
+
+// Rounds won, drawn and lost.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub won: usize,
+    pub drawn: usize,
+    pub lost: usize,
+}
+
+impl Record {
+    fn add(&mut self, other: Record) {
+        self.won += other.won;
+        self.drawn += other.drawn;
+        self.lost += other.lost;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standings {
+    pub names: Vec<String>,
+    // the players' scores summed over all their matches
+    pub scores: Vec<u64>,
+    pub records: Vec<Record>,
+    // head_to_head[a][b] is player a's score and record against player b
+    pub head_to_head: Vec<Vec<(u64, Record)>>,
+}
+
+// One player's side of a match.
+struct Side<'a> {
+    strategy: &'a Strategy,
+    rng: u64,
+    round: usize,
+    // the shapes played in the last round, mine first
+    last: Option<(Shape, Shape)>,
+    // how often the opponent has played each shape so far
+    counts: Vec<usize>,
+}
+
+impl Side<'_> {
+    fn play(&mut self, rules: &RuleSet) -> Shape {
+        let round = self.round;
+        match self.strategy {
+            Strategy::Fixed(shapes) if shapes.is_empty() => Shape(0),
+            Strategy::Fixed(shapes) => shapes[round % shapes.len()],
+            Strategy::Random { .. } => {
+                Shape((splitmix64(&mut self.rng) % rules.shapes().count() as u64) as usize)
+            }
+            Strategy::FrequencyCounter => {
+                let mut most = 0;
+                for shape in 1..self.counts.len() {
+                    if self.counts[shape] > self.counts[most] {
+                        most = shape;
+                    }
+                }
+                best_response(rules, Shape(most))
+            }
+            Strategy::WinStayLoseShift => match self.last {
+                Some((mine, theirs)) if rules.outcome(mine, theirs) == Outcome::Win => mine,
+                Some((_, theirs)) => best_response(rules, theirs),
+                None => Shape(0),
+            },
+        }
+    }
+
+    fn observe(&mut self, mine: Shape, theirs: Shape) {
+        self.round += 1;
+        self.last = Some((mine, theirs));
+        self.counts[theirs.0] += 1;
+    }
+}
+
+// Plays every pair of players against each other for `rounds` rounds.
+pub fn round_robin(rules: &RuleSet, players: &[Player], rounds: usize) -> Standings {
+    let n = players.len();
+    let mut head_to_head = vec![vec![(0, Record::default()); n]; n];
+    for a in 0..n {
+        for b in a + 1..n {
+            let mut sides = [a, b].map(|p| Side {
+                strategy: &players[p].strategy,
+                // every match of a random player goes differently, but the same every time
+                rng: match players[p].strategy {
+                    Strategy::Random { seed } => seed ^ ((a * n + b) as u64) << 32,
+                    _ => 0,
+                },
+                round: 0,
+                last: None,
+                counts: vec![0; rules.shapes().count()],
+            });
+            for _ in 0..rounds {
+                let shapes = [sides[0].play(rules), sides[1].play(rules)];
+                for (i, (me, them)) in [(a, b), (b, a)].into_iter().enumerate() {
+                    let (mine, theirs) = (shapes[i], shapes[1 - i]);
+                    let (score, record) = &mut head_to_head[me][them];
+                    *score += rules.score(mine, theirs) as u64;
+                    match rules.outcome(mine, theirs) {
+                        Outcome::Win => record.won += 1,
+                        Outcome::Draw => record.drawn += 1,
+                        Outcome::Lose => record.lost += 1,
+                    }
+                    sides[i].observe(mine, theirs);
+                }
+            }
+        }
+    }
+
+    let mut records = vec![Record::default(); n];
+    for (record, row) in records.iter_mut().zip(&head_to_head) {
+        row.iter().for_each(|&(_, r)| record.add(r));
+    }
+    Standings {
+        names: players.iter().map(|p| p.name.clone()).collect(),
+        scores: head_to_head
+            .iter()
+            .map(|row| row.iter().map(|(score, _)| score).sum())
+            .collect(),
+        records,
+        head_to_head,
+    }
+}
+
+impl Standings {
+    // The standings, highest score first, and each player's won-drawn-lost rounds against each
+    // other player.
+    pub fn table(&self) -> String {
+        let width = self.names.iter().map(|name| name.len()).max().unwrap_or(0);
+        let mut order: Vec<usize> = (0..self.names.len()).collect();
+        order.sort_by_key(|&p| Reverse(self.scores[p]));
+
+        let mut s = String::new();
+        let _ = writeln!(
+            s,
+            "{:<w$}  {:>8}  {:>6}  {:>6}  {:>6}",
+            "player",
+            "score",
+            "won",
+            "drawn",
+            "lost",
+            w = width
+        );
+        for &p in &order {
+            let r = self.records[p];
+            let _ = writeln!(
+                s,
+                "{:<w$}  {:>8}  {:>6}  {:>6}  {:>6}",
+                self.names[p],
+                self.scores[p],
+                r.won,
+                r.drawn,
+                r.lost,
+                w = width
+            );
+        }
+
+        let _ = writeln!(s, "\nhead to head, rounds won-drawn-lost:");
+        let cells: Vec<Vec<String>> = order
+            .iter()
+            .map(|&a| {
+                order
+                    .iter()
+                    .map(|&b| match self.head_to_head[a][b].1 {
+                        _ if a == b => String::from("-"),
+                        r => format!("{}-{}-{}", r.won, r.drawn, r.lost),
+                    })
+                    .collect()
+            })
+            .collect();
+        let cell_width = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.len())
+            .chain(order.iter().map(|&p| self.names[p].len()))
+            .max()
+            .unwrap_or(0);
+        let _ = write!(s, "{:<w$}", "", w = width);
+        for &b in &order {
+            let _ = write!(s, "  {:>w$}", self.names[b], w = cell_width);
+        }
+        s.push('\n');
+        for (&a, row) in order.iter().zip(&cells) {
+            let _ = write!(s, "{:<w$}", self.names[a], w = width);
+            for cell in row {
+                let _ = write!(s, "  {:>w$}", cell, w = cell_width);
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+// https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rounds, AsShape};
+
+    fn players(guide: Vec<Shape>, seed: u64) -> Vec<Player> {
+        [
+            ("guide", Strategy::Fixed(guide)),
+            ("random", Strategy::Random { seed }),
+            ("frequency", Strategy::FrequencyCounter),
+            ("win-stay", Strategy::WinStayLoseShift),
+        ]
+        .map(|(name, strategy)| Player {
+            name: String::from(name),
+            strategy,
+        })
+        .to_vec()
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let rules = RuleSet::rock_paper_scissors();
+        let rocks = players(vec![Shape(0)], 1);
+        let standings = round_robin(&rules, &rocks, 10);
+        // the frequency counter takes them for Rock from the start and plays Paper
+        let (score, record) = standings.head_to_head[2][0];
+        assert_eq!(score, 10 * (2 + 6));
+        assert_eq!(
+            record,
+            Record {
+                won: 10,
+                drawn: 0,
+                lost: 0
+            }
+        );
+        assert_eq!(standings.head_to_head[0][2].1.lost, 10);
+        // win-stay, lose-shift starts with Rock too, then shifts to Paper and stays
+        let (score, record) = standings.head_to_head[3][0];
+        assert_eq!(score, 1 + 3 + 9 * (2 + 6));
+        assert_eq!((record.won, record.drawn), (9, 1));
+        for p in 0..rocks.len() {
+            let total: u64 = standings.head_to_head[p].iter().map(|(s, _)| s).sum();
+            assert_eq!(standings.scores[p], total);
+            let r = standings.records[p];
+            assert_eq!(r.won + r.drawn + r.lost, 3 * 10);
+        }
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let rules = RuleSet::rock_paper_scissors();
+        let guide = vec![Shape(1), Shape(0), Shape(2)];
+        let first = round_robin(&rules, &players(guide.clone(), 7), 100);
+        assert_eq!(round_robin(&rules, &players(guide.clone(), 7), 100), first);
+        assert_ne!(round_robin(&rules, &players(guide, 8), 100), first);
+    }
+
+    #[test]
+    fn test_table() {
+        let rules = RuleSet::rock_paper_scissors();
+        let input = include_str!("../input");
+        let guide = rounds(input, &rules, &AsShape)
+            .into_iter()
+            .map(|(_, yours)| yours)
+            .collect();
+        let standings = round_robin(&rules, &players(guide, 2022), 1000);
+        snapshot::assert_snapshot!("input-tournament", standings.table());
+    }
+}
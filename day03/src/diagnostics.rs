@@ -0,0 +1,387 @@
+// Diagnostics for rucksacks that break the rules: a rucksack should split into two compartments
+// of the same size with exactly one kind of item in both, and a group should have exactly one
+// kind of item, its badge, that every rucksack in it carries.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::{compartments, parse_input, ItemSet, PriorityScheme};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+// Moving `count` of `item` into the compartment `to`, out of the other one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    pub to: Compartment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    pub len: usize,
+    // the items in both compartments, lowest priority first
+    pub shared: Vec<char>,
+    // the fewest moves that make the rucksack valid, none if it already is, or None when no
+    // moves can
+    pub fix: Option<Vec<Move>>,
+}
+
+impl Rucksack {
+    pub fn is_odd(&self) -> bool {
+        !self.len.is_multiple_of(2)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.is_odd() && self.shared.len() == 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    // the 0-based index of the first rucksack in the group
+    pub first: usize,
+    pub size: usize,
+    // the items every rucksack of the group carries, lowest priority first
+    pub badges: Vec<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub rucksacks: Vec<Rucksack>,
+    pub groups: Vec<Group>,
+    // how many rucksacks are left over at the end, too few to make a group
+    pub ungrouped: usize,
+}
+
+pub fn diagnose(
+    input: &str,
+    scheme: &PriorityScheme,
+    group_size: usize,
+) -> Result<Diagnostics, String> {
+    let rucksacks = parse_input(input);
+    if group_size == 0 {
+        return Err(format!(
+            "{} rucksacks cannot be split into groups of 0",
+            rucksacks.len()
+        ));
+    }
+    let mut sets = Vec::new();
+    let mut checked = Vec::new();
+    for (i, rucksack) in rucksacks.iter().enumerate() {
+        let (set, rucksack) =
+            check(rucksack, scheme).map_err(|e| format!("rucksack {}: {}", i + 1, e))?;
+        sets.push(set);
+        checked.push(rucksack);
+    }
+    let groups = sets
+        .chunks_exact(group_size)
+        .enumerate()
+        .map(|(i, group)| Group {
+            first: i * group_size,
+            size: group_size,
+            badges: scheme
+                .items(group.iter().fold(ItemSet::ALL, |a, &b| a & b))
+                .collect(),
+        })
+        .collect();
+    Ok(Diagnostics {
+        rucksacks: checked,
+        groups,
+        ungrouped: rucksacks.len() % group_size,
+    })
+}
+
+// Checks a rucksack, returning the set of its items too.
+fn check(rucksack: &str, scheme: &PriorityScheme) -> Result<(ItemSet, Rucksack), String> {
+    let (first, second) = compartments(rucksack);
+    let (first, second) = (scheme.item_set(first)?, scheme.item_set(second)?);
+    let priorities = rucksack
+        .chars()
+        .map(|item| scheme.priority(item))
+        .collect::<Result<Vec<u32>, String>>()?;
+    let checked = Rucksack {
+        len: priorities.len(),
+        shared: scheme.items(first & second).collect(),
+        fix: fix(&priorities, scheme),
+    };
+    Ok((ItemSet(first.0 | second.0), checked))
+}
+
+// The fewest moves after which exactly one kind of item is in both compartments, keeping them the
+// same size. Only the number of each kind of item in each compartment matters, so this chooses how
+// many of each kind to leave in the first compartment: all or none of every kind but the one to
+// share, which needs at least one in each.
+fn fix(priorities: &[u32], scheme: &PriorityScheme) -> Option<Vec<Move>> {
+    let len = priorities.len();
+    if !len.is_multiple_of(2) {
+        return None;
+    }
+    let half = len / 2;
+    let mut total = vec![0usize; scheme.len()];
+    let mut first = vec![0usize; scheme.len()];
+    for (i, &priority) in priorities.iter().enumerate() {
+        let p = priority as usize - 1;
+        total[p] += 1;
+        if i < half {
+            first[p] += 1;
+        }
+    }
+    let kinds: Vec<usize> = (0..scheme.len()).filter(|&p| total[p] > 0).collect();
+
+    // cost[k][shared][n]: the fewest moves that leave n of the first k kinds in the first
+    // compartment, with one of those kinds in both if shared is 1
+    let mut cost = vec![[vec![None; half + 1], vec![None; half + 1]]];
+    cost[0][0][0] = Some(0);
+    for &p in &kinds {
+        let before = cost.last().unwrap();
+        let mut after = [vec![None; half + 1], vec![None; half + 1]];
+        for shared in 0..2 {
+            for n in 0..=half {
+                let Some(moves) = before[shared][n] else {
+                    continue;
+                };
+                for keep in 0..=total[p].min(half - n) {
+                    let split = (keep > 0 && keep < total[p]) as usize;
+                    if shared + split > 1 {
+                        continue;
+                    }
+                    let moves = moves + first[p].abs_diff(keep);
+                    let best = &mut after[shared + split][n + keep];
+                    if best.is_none_or(|best| moves < best) {
+                        *best = Some(moves);
+                    }
+                }
+            }
+        }
+        cost.push(after);
+    }
+
+    // walk back through the choices that gave the fewest moves
+    let mut moves = Vec::new();
+    let mut shared = 1;
+    let mut n = half;
+    for (k, &p) in kinds.iter().enumerate().rev() {
+        let target = cost[k + 1][shared][n]?;
+        let keep = (0..=total[p].min(n))
+            .find(|&keep| {
+                let split = (keep > 0 && keep < total[p]) as usize;
+                split <= shared
+                    && cost[k][shared - split][n - keep]
+                        .is_some_and(|m| m + first[p].abs_diff(keep) == target)
+            })
+            .unwrap();
+        if keep != first[p] {
+            moves.push(Move {
+                item: scheme.item(p as u32 + 1).unwrap(),
+                count: first[p].abs_diff(keep),
+                to: if keep > first[p] {
+                    Compartment::First
+                } else {
+                    Compartment::Second
+                },
+            });
+        }
+        shared -= (keep > 0 && keep < total[p]) as usize;
+        n -= keep;
+    }
+    moves.reverse();
+    Some(moves)
+}
+
+pub fn report(input: &str, scheme: &PriorityScheme, group_size: usize) -> Result<String, String> {
+    let diagnostics = diagnose(input, scheme, group_size)?;
+    let mut s = String::new();
+    let valid = diagnostics
+        .rucksacks
+        .iter()
+        .filter(|r| r.is_valid())
+        .count();
+    let _ = writeln!(
+        s,
+        "rucksacks: {}, {} valid",
+        diagnostics.rucksacks.len(),
+        valid
+    );
+    for (i, rucksack) in diagnostics.rucksacks.iter().enumerate() {
+        let _ = write!(s, "rucksack {}: ", i + 1);
+        if rucksack.is_odd() {
+            let _ = writeln!(s, "odd length {}, cannot be split evenly", rucksack.len);
+            continue;
+        }
+        let _ = write!(s, "{}", candidates(&rucksack.shared));
+        match &rucksack.fix {
+            _ if rucksack.is_valid() => {}
+            Some(moves) => {
+                let moves: Vec<String> = moves
+                    .iter()
+                    .map(|m| {
+                        let to = match m.to {
+                            Compartment::First => "first",
+                            Compartment::Second => "second",
+                        };
+                        format!("{} {} to the {} compartment", m.count, m.item, to)
+                    })
+                    .collect();
+                let _ = write!(s, ", move {}", moves.join(" and "));
+            }
+            None => {
+                let _ = write!(s, ", moving items cannot fix it");
+            }
+        }
+        let _ = writeln!(s);
+    }
+    let single = diagnostics
+        .groups
+        .iter()
+        .filter(|g| g.badges.len() == 1)
+        .count();
+    let _ = writeln!(
+        s,
+        "groups of {}: {}, {} with one badge",
+        group_size,
+        diagnostics.groups.len(),
+        single
+    );
+    for (i, group) in diagnostics.groups.iter().enumerate() {
+        let _ = writeln!(
+            s,
+            "group {} (rucksacks {}-{}): {}",
+            i + 1,
+            group.first + 1,
+            group.first + group.size,
+            candidates(&group.badges)
+        );
+    }
+    if diagnostics.ungrouped > 0 {
+        let _ = writeln!(
+            s,
+            "rucksacks left over, too few for a group: {}",
+            diagnostics.ungrouped
+        );
+    }
+    Ok(s)
+}
+
+fn candidates(items: &[char]) -> String {
+    match items.len() {
+        0 => String::from("none"),
+        1 => String::from(items[0]),
+        n => {
+            let items: Vec<String> = items.iter().map(|&item| String::from(item)).collect();
+            format!("{} ({} candidates)", items.join(", "), n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn checked(rucksack: &str) -> Rucksack {
+        check(rucksack, &PriorityScheme::standard()).unwrap().1
+    }
+
+    #[test]
+    fn test_example_diagnostics() {
+        let scheme = PriorityScheme::standard();
+        let diagnostics = diagnose(include_str!("../input-example"), &scheme, 3).unwrap();
+        assert!(diagnostics.rucksacks.iter().all(|r| r.is_valid()));
+        assert!(diagnostics
+            .rucksacks
+            .iter()
+            .all(|r| r.fix == Some(Vec::new())));
+        assert_eq!(diagnostics.rucksacks[0].shared, ['p']);
+        assert_eq!(diagnostics.groups[1].badges, ['Z']);
+        assert_eq!(diagnostics.ungrouped, 0);
+
+        let diagnostics = diagnose(include_str!("../input-example"), &scheme, 4).unwrap();
+        assert_eq!(diagnostics.groups.len(), 1);
+        assert_eq!(diagnostics.ungrouped, 2);
+
+        assert_eq!(
+            diagnose(include_str!("../input-example"), &scheme, 0),
+            Err("6 rucksacks cannot be split into groups of 0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let odd = checked("abcde");
+        assert!(odd.is_odd());
+        assert_eq!(odd.fix, None);
+
+        assert_eq!(checked("abca").shared, ['a']);
+        assert!(checked("abca").is_valid());
+
+        let both = checked("abab");
+        assert_eq!(both.shared, ['a', 'b']);
+        // sharing one of them leaves one of the other on each side too
+        assert_eq!(both.fix, None);
+
+        let neither = checked("aabc");
+        assert!(neither.shared.is_empty());
+        assert_eq!(
+            neither.fix,
+            Some(vec![
+                Move {
+                    item: 'a',
+                    count: 1,
+                    to: Compartment::Second
+                },
+                Move {
+                    item: 'b',
+                    count: 1,
+                    to: Compartment::First
+                },
+            ])
+        );
+
+        // nothing appears twice, so nothing can be in both
+        assert_eq!(checked("abcd").fix, None);
+
+        let scheme = PriorityScheme::parse("0-9 ★").unwrap();
+        let (set, digits) = check("★★12", &scheme).unwrap();
+        assert_eq!(scheme.items(set).collect::<String>(), "12★");
+        assert_eq!(
+            digits.fix,
+            Some(vec![
+                Move {
+                    item: '1',
+                    count: 1,
+                    to: Compartment::First
+                },
+                Move {
+                    item: '★',
+                    count: 1,
+                    to: Compartment::Second
+                },
+            ])
+        );
+        assert_eq!(
+            diagnose("abca\nab1a\n", &PriorityScheme::standard(), 1),
+            Err("rucksack 2: unknown item '1'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        snapshot::assert_snapshot!(
+            "diagnostics",
+            report(
+                "vJrwpWtwJgWrhcsFMMfFFhFp\nabab\naabc\nabcde\nxyzxyz\n",
+                &PriorityScheme::standard(),
+                2
+            )
+            .unwrap()
+        );
+    }
+}
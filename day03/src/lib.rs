@@ -0,0 +1,312 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::BitAnd;
+
+pub mod diagnostics;
+pub mod priority;
+
+pub use priority::PriorityScheme;
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    misplaced(input, &PriorityScheme::standard()).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn part2(input: &str) -> u32 {
+    badges(input, &PriorityScheme::standard(), 3).unwrap_or_else(|e| panic!("{}", e))
+}
+
+// The sum of the priorities of the items in both compartments of each rucksack.
+pub fn misplaced(input: &str, scheme: &PriorityScheme) -> Result<u32, String> {
+    let mut sum = 0;
+    for (i, rucksack) in parse_input(input).into_iter().enumerate() {
+        let (first, second) = compartments(rucksack);
+        let shared = scheme
+            .item_set(first)
+            .and_then(|first| Ok(first & scheme.item_set(second)?))
+            .map_err(|e| format!("rucksack {}: {}", i + 1, e))?;
+        match shared.priorities().next() {
+            Some(priority) => sum += priority,
+            None => {
+                return Err(format!(
+                    "rucksack {} has no item in both compartments",
+                    i + 1
+                ))
+            }
+        }
+    }
+    Ok(sum)
+}
+
+// The sum of the priorities of the badges, the item all rucksacks of a group have in common, with
+// every `group_size` rucksacks forming a group.
+pub fn badges(input: &str, scheme: &PriorityScheme, group_size: usize) -> Result<u32, String> {
+    let rucksacks = parse_input(input);
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(format!(
+            "{} rucksacks cannot be split into groups of {}",
+            rucksacks.len(),
+            group_size
+        ));
+    }
+    let mut sum = 0;
+    for (i, group) in rucksacks.chunks_exact(group_size).enumerate() {
+        let mut badge = ItemSet::ALL;
+        for (j, rucksack) in group.iter().enumerate() {
+            let items = scheme
+                .item_set(rucksack)
+                .map_err(|e| format!("rucksack {}: {}", i * group_size + j + 1, e))?;
+            badge = badge & items;
+        }
+        match badge.priorities().next() {
+            Some(priority) => sum += priority,
+            None => return Err(format!("group {} has no item in common", i + 1)),
+        }
+    }
+    Ok(sum)
+}
+
+// Splits a rucksack into its two compartments, the second one an item longer if there is an odd
+// number of them.
+pub fn compartments(rucksack: &str) -> (&str, &str) {
+    let half = rucksack.chars().count() / 2;
+    let at = rucksack
+        .char_indices()
+        .nth(half)
+        .map_or(rucksack.len(), |(at, _)| at);
+    rucksack.split_at(at)
+}
+
+// A set of items as a bit mask, with the item of priority p in bit p - 1. Sets are made and their
+// items listed by a PriorityScheme, see PriorityScheme::item_set and PriorityScheme::items.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    // every bit set, so intersecting with it changes nothing
+    pub const ALL: ItemSet = ItemSet(u64::MAX);
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // the priorities of the items, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (0..64)
+            .filter(move |bit| self.0 & (1 << bit) != 0)
+            .map(|bit| bit + 1)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+// Narrates part 1 rucksack by rucksack, for at most `steps` rucksacks.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    explain_with(input, &PriorityScheme::standard(), steps)
+}
+
+// Like explain, with the priorities of `scheme`. A rucksack holding an item the scheme does not
+// have ends the narration.
+pub fn explain_with(input: &str, scheme: &PriorityScheme, steps: usize) -> Vec<String> {
+    let mut total = 0;
+    let mut narration = Vec::new();
+    for (i, rucksack) in parse_input(input).into_iter().take(steps).enumerate() {
+        let (first, second) = compartments(rucksack);
+        let shared = match scheme
+            .item_set(first)
+            .and_then(|first| Ok(first & scheme.item_set(second)?))
+        {
+            Ok(shared) => shared,
+            Err(e) => {
+                narration.push(format!("rucksack {}: {}", i + 1, e));
+                break;
+            }
+        };
+        let Some(priority) = shared.priorities().next() else {
+            narration.push(format!(
+                "rucksack {}: {} | {} share no item, {} in total",
+                i + 1,
+                first,
+                second,
+                total
+            ));
+            continue;
+        };
+        total += priority;
+        narration.push(format!(
+            "rucksack {}: {} | {} share {}, priority {}, {} in total",
+            i + 1,
+            first,
+            second,
+            scheme.items(shared).next().unwrap_or('?'),
+            priority,
+            total
+        ));
+    }
+    narration
+}
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.split_whitespace().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_example_parse() {
+        let rucksacks = parse_input(include_str!("../input-example"));
+        assert_eq!(rucksacks.len(), 6);
+    }
+
+    #[test]
+    fn test_priority() {
+        let scheme = PriorityScheme::standard();
+        assert_eq!(scheme.priority('p'), Ok(16));
+        assert_eq!(scheme.priority('L'), Ok(38));
+        assert_eq!(scheme.priority('P'), Ok(42));
+        assert_eq!(scheme.priority('v'), Ok(22));
+        assert_eq!(scheme.priority('t'), Ok(20));
+        assert_eq!(scheme.priority('s'), Ok(19));
+    }
+
+    #[test]
+    fn test_item_set() {
+        let scheme = PriorityScheme::standard();
+        let set = |items| scheme.item_set(items).unwrap();
+        let shared = set("vJrwpWtwJgWr") & set("hcsFMMfFFhFp");
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared.priorities().collect::<Vec<u32>>(), [16]);
+        assert_eq!(set("aZ").0, 1 | 1 << 51);
+        assert!((set("ab") & set("cd")).is_empty());
+        assert_eq!(set("").priorities().next(), None);
+        assert_eq!(scheme.items(set("Zza")).collect::<String>(), "azZ");
+    }
+
+    #[test]
+    fn test_badges() {
+        let input = include_str!("../input-example");
+        let scheme = PriorityScheme::standard();
+        assert_eq!(badges(input, &scheme, 3), Ok(18 + 52));
+        assert_eq!(
+            badges(input, &scheme, 4),
+            Err("6 rucksacks cannot be split into groups of 4".to_string())
+        );
+        assert_eq!(
+            badges(input, &scheme, 0),
+            Err("6 rucksacks cannot be split into groups of 0".to_string())
+        );
+        assert_eq!(
+            badges("abab\ncdcd\n", &scheme, 2),
+            Err("group 1 has no item in common".to_string())
+        );
+    }
+
+    #[test]
+    fn test_misplaced() {
+        let scheme = PriorityScheme::standard();
+        assert_eq!(
+            misplaced(include_str!("../input-example"), &scheme),
+            Ok(157)
+        );
+        assert_eq!(
+            misplaced("abca\nab1a\n", &scheme),
+            Err("rucksack 2: unknown item '1'".to_string())
+        );
+        assert_eq!(
+            misplaced("abcd\n", &scheme),
+            Err("rucksack 1 has no item in both compartments".to_string())
+        );
+        assert_eq!(
+            badges("abca\nab1a\n", &scheme, 2),
+            Err("rucksack 2: unknown item '1'".to_string())
+        );
+
+        let scheme = PriorityScheme::parse("0-9 ★").unwrap();
+        assert_eq!(misplaced("1221\n★1★4\n", &scheme), Ok(2 + 11));
+        assert_eq!(badges("1221\n★1★4\n", &scheme, 2), Ok(2));
+        assert_eq!(badges("1★\n★2\n", &scheme, 2), Ok(11));
+    }
+
+    #[test]
+    fn test_compartments() {
+        assert_eq!(compartments("abcd"), ("ab", "cd"));
+        assert_eq!(compartments("abc"), ("a", "bc"));
+        assert_eq!(compartments("★1☆2"), ("★1", "☆2"));
+        assert_eq!(compartments(""), ("", ""));
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 157);
+        assert_eq!(part2_answer, 70);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 2);
+        assert_eq!(
+            steps,
+            [
+                "rucksack 1: vJrwpWtwJgWr | hcsFMMfFFhFp share p, priority 16, 16 in total",
+                "rucksack 2: jqHRNqRjqzjGDLGL | rsFMfFZSrLrFZsSL share L, priority 38, 54 in total",
+            ]
+        );
+        assert_eq!(
+            explain("abcd\naa\n", 2),
+            [
+                "rucksack 1: ab | cd share no item, 0 in total",
+                "rucksack 2: a | a share a, priority 1, 1 in total",
+            ]
+        );
+        assert_eq!(
+            explain(
+                "abab
+ab1b
+abab
+",
+                3
+            ),
+            [
+                "rucksack 1: ab | ab share a, priority 1, 1 in total",
+                "rucksack 2: unknown item '1'",
+            ]
+        );
+        let scheme = PriorityScheme::parse("0-9").unwrap();
+        assert_eq!(
+            explain_with(
+                "1221
+", &scheme, 1
+            ),
+            ["rucksack 1: 12 | 21 share 1, priority 2, 2 in total"]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 7997);
+        assert_eq!(part2_answer, 2545);
+    }
+}
@@ -1,85 +1,77 @@
-use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::process;
 
-fn main() {
-    let (part1_answer, part2_answer) = run(include_str!("../input"));
-    println!("part 1 answer: {}", part1_answer);
-    println!("part 2 answer: {}", part2_answer);
-}
+use day03::PriorityScheme;
 
-fn run(input: &'static str) -> (u32, u32) {
-    let mut part1_answer: u32 = 0;
-    let mut part2_answer: u32 = 0;
+const USAGE: &str = "usage: day03 [--priorities <file>] [--group-size <n>] [--diagnose] [<input>]
 
-    let rucksacks = parse_input(input);
-    // part one
-    for rucksack in &rucksacks {
-        let r = rucksack.to_string();
-        let n = r.len() / 2;
-        let mut c = r.chars();
-        let r1: HashSet<char> = c.by_ref().take(n).collect();
-        let r2: HashSet<char> = c.by_ref().take(n).collect();
-        let err = r1.intersection(&r2).next().unwrap();
-        part1_answer += get_priority(*err);
-    }
+--priorities takes the items and their priorities from <file>, which lists the
+items in order of priority, such as \"0-9 a-z\", instead of a-z then A-Z. With
+--group-size the badges of part 2 are found in groups of <n> rucksacks
+instead of 3, and with --diagnose every rucksack and group is checked and the
+fewest moves to fix each invalid rucksack are listed instead of the answers.";
 
-    // part two
-    let mut ri = rucksacks.iter().peekable();
-    while ri.peek().is_some() {
-        let r0: HashSet<char> = ri.next().unwrap().chars().collect();
-        let r1: HashSet<char> = ri.next().unwrap().chars().collect();
-        let r2: HashSet<char> = ri.next().unwrap().chars().collect();
-        let a: HashSet<char> = r0.intersection(&r1).copied().collect();
-        let mut b = a.intersection(&r2);
-        let badge = b.next().unwrap();
-        part2_answer += get_priority(*badge);
+fn main() {
+    let mut path = None;
+    let mut scheme = PriorityScheme::standard();
+    let mut group_size = 3;
+    let mut diagnose = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--priorities" => {
+                let file = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--priorities expects a file"));
+                let text = fs::read_to_string(&file).unwrap_or_else(|e| {
+                    eprintln!("{}: {}", file, e);
+                    process::exit(1);
+                });
+                scheme = PriorityScheme::parse(&text).unwrap_or_else(|e| {
+                    eprintln!("{}: {}", file, e);
+                    process::exit(1);
+                });
+            }
+            "--group-size" => {
+                group_size = match args.next().and_then(|n| n.parse::<usize>().ok()) {
+                    Some(n) if n > 0 => n,
+                    _ => usage_error("--group-size expects a number of rucksacks"),
+                };
+            }
+            "--diagnose" => diagnose = true,
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => path = Some(arg),
+        }
     }
-
-    (part1_answer, part2_answer)
-}
-
-fn parse_input(input: &'static str) -> Vec<&'static str> {
-    input.split_whitespace().collect()
-}
-
-fn get_priority(c: char) -> u32 {
-    if c.is_lowercase() {
-        (c as u32) - 96
+    let input = match path {
+        Some(path) => fs::read_to_string(path).unwrap(),
+        None => include_str!("../input").to_string(),
+    };
+    let result = if diagnose {
+        day03::diagnostics::report(&input, &scheme, group_size)
     } else {
-        (c as u32) - 38
+        day03::misplaced(&input, &scheme).and_then(|part1_answer| {
+            let part2_answer = day03::badges(&input, &scheme, group_size)?;
+            Ok(format!(
+                "part 1 answer: {}\npart 2 answer: {}\n",
+                part1_answer, part2_answer
+            ))
+        })
+    };
+    match result {
+        Ok(output) => print!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example_parse() {
-        let rucksacks = parse_input(include_str!("../input-example"));
-        assert_eq!(rucksacks.len(), 6);
-    }
-
-    #[test]
-    fn test_get_priority() {
-        assert_eq!(get_priority('p'), 16);
-        assert_eq!(get_priority('L'), 38);
-        assert_eq!(get_priority('P'), 42);
-        assert_eq!(get_priority('v'), 22);
-        assert_eq!(get_priority('t'), 20);
-        assert_eq!(get_priority('s'), 19);
-    }
-
-    #[test]
-    fn test_example_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
-        assert_eq!(part1_answer, 157);
-        assert_eq!(part2_answer, 70);
-    }
-
-    #[test]
-    fn test_input_answer() {
-        let (part1_answer, part2_answer) = run(include_str!("../input"));
-        assert_eq!(part1_answer, 7997);
-        assert_eq!(part2_answer, 2545);
-    }
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
 }
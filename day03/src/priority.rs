@@ -0,0 +1,172 @@
+// Priority schemes: the items that can be packed and their priorities, from 1 for the first.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::ItemSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityScheme {
+    // the items in order of priority
+    items: Vec<char>,
+}
+
+impl Default for PriorityScheme {
+    fn default() -> PriorityScheme {
+        PriorityScheme::standard()
+    }
+}
+
+impl PriorityScheme {
+    // a to z have priorities 1 to 26 and A to Z 27 to 52
+    pub fn standard() -> PriorityScheme {
+        PriorityScheme {
+            items: ('a'..='z').chain('A'..='Z').collect(),
+        }
+    }
+
+    // Parses the items in order of priority, separated by whitespace over any number of lines:
+    //
+    //     0-9 a-z
+    //     ★ ☆
+    //
+    // A range like 0-9 stands for every character from one end to the other, and anything else
+    // for each of its characters in turn.
+    pub fn parse(text: &str) -> Result<PriorityScheme, String> {
+        let mut items: Vec<char> = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            for token in line.split_whitespace() {
+                let too_many = || {
+                    format!(
+                        "line {}: more than 64 items, at most 64 fit in an item set",
+                        i + 1
+                    )
+                };
+                let chars: Vec<char> = token.chars().collect();
+                let new: Vec<char> = match chars[..] {
+                    [from, '-', to] if from <= to => {
+                        // checked before the range is listed out, as it may cover every character
+                        if to as u32 - from as u32 + 1 > (64 - items.len()) as u32 {
+                            return Err(too_many());
+                        }
+                        (from..=to).collect()
+                    }
+                    [from, '-', to] => {
+                        return Err(format!("line {}: {}-{} runs backwards", i + 1, from, to))
+                    }
+                    _ => chars,
+                };
+                for item in new {
+                    if items.contains(&item) {
+                        return Err(format!("line {}: {} is listed twice", i + 1, item));
+                    }
+                    if items.len() == 64 {
+                        return Err(too_many());
+                    }
+                    items.push(item);
+                }
+            }
+        }
+        if items.is_empty() {
+            return Err("no items".to_string());
+        }
+        Ok(PriorityScheme { items })
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn priority(&self, item: char) -> Result<u32, String> {
+        match self.items.iter().position(|&i| i == item) {
+            Some(i) => Ok(i as u32 + 1),
+            None => Err(format!("unknown item {:?}", item)),
+        }
+    }
+
+    pub fn item(&self, priority: u32) -> Option<char> {
+        let i = (priority as usize).checked_sub(1)?;
+        self.items.get(i).copied()
+    }
+
+    pub fn item_set(&self, items: &str) -> Result<ItemSet, String> {
+        let mut set = ItemSet::default();
+        for item in items.chars() {
+            set.0 |= 1 << (self.priority(item)? - 1);
+        }
+        Ok(set)
+    }
+
+    // the items of a set, lowest priority first, leaving out priorities the scheme has no item for
+    pub fn items(&self, set: ItemSet) -> impl Iterator<Item = char> + '_ {
+        set.priorities().filter_map(|p| self.item(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard() {
+        let scheme = PriorityScheme::standard();
+        assert_eq!(scheme.len(), 52);
+        for (i, c) in ('a'..='z').chain('A'..='Z').enumerate() {
+            assert_eq!(scheme.priority(c), Ok(i as u32 + 1));
+            assert_eq!(scheme.item(i as u32 + 1), Some(c));
+        }
+        assert_eq!(scheme.priority('1'), Err("unknown item '1'".to_string()));
+        assert_eq!(scheme.item(0), None);
+        assert_eq!(scheme.item(53), None);
+        assert_eq!(scheme.item_set("aZ"), Ok(ItemSet(1 | 1 << 51)));
+        assert_eq!(scheme.item_set("a-"), Err("unknown item '-'".to_string()));
+    }
+
+    #[test]
+    fn test_parse() {
+        let scheme = PriorityScheme::parse("# digits, then symbols\n0-9\n★☆ -\n").unwrap();
+        assert_eq!(scheme.len(), 13);
+        assert_eq!(scheme.priority('0'), Ok(1));
+        assert_eq!(scheme.priority('9'), Ok(10));
+        assert_eq!(scheme.priority('☆'), Ok(12));
+        assert_eq!(scheme.priority('-'), Ok(13));
+        let set = scheme.item_set("☆9☆").unwrap();
+        assert_eq!(scheme.items(set).collect::<String>(), "9☆");
+        assert_eq!(scheme.items(ItemSet::ALL).count(), 13);
+
+        assert_eq!(
+            PriorityScheme::parse("a-z A-Z"),
+            Ok(PriorityScheme::standard())
+        );
+        assert_eq!(
+            PriorityScheme::parse("# none\n"),
+            Err("no items".to_string())
+        );
+        assert_eq!(
+            PriorityScheme::parse("abc\nxb"),
+            Err("line 2: b is listed twice".to_string())
+        );
+        assert_eq!(
+            PriorityScheme::parse("9-0"),
+            Err("line 1: 9-0 runs backwards".to_string())
+        );
+        assert_eq!(
+            PriorityScheme::parse("a-z A-Z 0-9 + - ."),
+            Err("line 1: more than 64 items, at most 64 fit in an item set".to_string())
+        );
+        assert_eq!(
+            PriorityScheme::parse("a-z\n!-\u{10FFFF}"),
+            Err("line 2: more than 64 items, at most 64 fit in an item set".to_string())
+        );
+        assert_eq!(PriorityScheme::parse("!-`").unwrap().len(), 64);
+    }
+}
@@ -0,0 +1,118 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    let mut part1_answer: u32 = 0;
+    for line in parse_input(input) {
+        let (p1a, p1b, p2a, p2b) = read_pair(line);
+        if p1a >= p2a && p1b <= p2b || p2a >= p1a && p2b <= p1b {
+            part1_answer += 1;
+        }
+    }
+    part1_answer
+}
+
+pub fn part2(input: &str) -> u32 {
+    let mut part2_answer: u32 = 0;
+    for line in parse_input(input) {
+        let (p1a, p1b, p2a, p2b) = read_pair(line);
+        if (p1a >= p2a && p1a <= p2b)
+            || (p1b >= p2a && p1b <= p2b)
+            || (p2a >= p1a && p2a <= p1b)
+            || (p2b >= p1a && p2b <= p1b)
+        {
+            part2_answer += 1;
+        }
+    }
+    part2_answer
+}
+
+// Narrates part 1 pair by pair, for at most `steps` pairs.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut contained = 0;
+    let mut narration = Vec::new();
+    for (i, line) in parse_input(input).into_iter().take(steps).enumerate() {
+        let (p1a, p1b, p2a, p2b) = read_pair(line);
+        let verdict = if p1a >= p2a && p1b <= p2b || p2a >= p1a && p2b <= p1b {
+            contained += 1;
+            "one range fully contains the other"
+        } else if p1a <= p2b && p2a <= p1b {
+            "the ranges overlap, but neither contains the other"
+        } else {
+            "the ranges do not overlap"
+        };
+        narration.push(format!(
+            "pair {}, {}: {}, {} so far",
+            i + 1,
+            line,
+            verdict,
+            contained
+        ));
+    }
+    narration
+}
+
+fn read_pair(line: &str) -> (u32, u32, u32, u32) {
+    let mut pair = line.split(',');
+    let p1 = pair.next().unwrap();
+    let p2 = pair.next().unwrap();
+    let mut p1s = p1.split('-');
+    let p1a = p1s.next().unwrap().parse::<u32>().unwrap();
+    let p1b = p1s.next().unwrap().parse::<u32>().unwrap();
+    let mut p2s = p2.split('-');
+    let p2a = p2s.next().unwrap().parse::<u32>().unwrap();
+    let p2b = p2s.next().unwrap().parse::<u32>().unwrap();
+    (p1a, p1b, p2a, p2b)
+}
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.trim_end().split('\n').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let lines = parse_input(include_str!("../input-example"));
+        assert_eq!(lines.len(), 6);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 2);
+        assert_eq!(part2_answer, 4);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 4);
+        assert_eq!(
+            steps,
+            [
+                "pair 1, 2-4,6-8: the ranges do not overlap, 0 so far",
+                "pair 2, 2-3,4-5: the ranges do not overlap, 0 so far",
+                "pair 3, 5-7,7-9: the ranges overlap, but neither contains the other, 0 so far",
+                "pair 4, 2-8,3-7: one range fully contains the other, 1 so far",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 556);
+        assert_eq!(part2_answer, 876);
+    }
+}
@@ -0,0 +1,222 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[derive(Debug)]
+pub struct Move {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub fn run(input: &str) -> (String, String) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> String {
+    let (mut stacks, commands) = parse_input(input);
+    for m in &commands {
+        for _ in 0..m.amount {
+            let item = stacks[m.from - 1].pop_back().unwrap();
+            stacks[m.to - 1].push_back(item);
+        }
+    }
+    answer(&stacks)
+}
+
+pub fn part2(input: &str) -> String {
+    let (mut stacks, commands) = parse_input(input);
+    for m in &commands {
+        let n = stacks[m.from - 1].len();
+        let items: Vec<char> = stacks[m.from - 1].drain(n - m.amount..n).collect();
+        items
+            .iter()
+            .for_each(|item| stacks[m.to - 1].push_back(*item));
+    }
+    answer(&stacks)
+}
+
+// Narrates part 1 move by move, for at most `steps` moves. Stacks are listed bottom to top.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let (mut stacks, commands) = parse_input(input);
+    let mut narration = Vec::new();
+    for m in commands.iter().take(steps) {
+        let mut moved = String::new();
+        for _ in 0..m.amount {
+            let item = stacks[m.from - 1].pop_back().unwrap();
+            stacks[m.to - 1].push_back(item);
+            moved.push(item);
+        }
+        let stacks: Vec<String> = stacks
+            .iter()
+            .enumerate()
+            .map(|(i, stack)| {
+                if stack.is_empty() {
+                    format!("{}: -", i + 1)
+                } else {
+                    format!("{}: {}", i + 1, stack.iter().collect::<String>())
+                }
+            })
+            .collect();
+        narration.push(format!(
+            "move {} from {} to {}: {} one by one, stacks are now {}",
+            m.amount,
+            m.from,
+            m.to,
+            moved,
+            stacks.join(", ")
+        ));
+    }
+    narration
+}
+
+// Draws the stacks as the puzzle input does, after the first `moves` moves of part 1.
+pub fn drawing(input: &str, moves: usize) -> String {
+    let (mut stacks, commands) = parse_input(input);
+    for m in commands.iter().take(moves) {
+        for _ in 0..m.amount {
+            let item = stacks[m.from - 1].pop_back().unwrap();
+            stacks[m.to - 1].push_back(item);
+        }
+    }
+    draw(&stacks)
+}
+
+fn draw(stacks: &[VecDeque<char>]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut lines = Vec::new();
+    for level in (0..height).rev() {
+        let crates: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(item) => format!("[{}]", item),
+                None => "   ".to_string(),
+            })
+            .collect();
+        lines.push(crates.join(" "));
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    lines.push(numbers.join(" "));
+    lines.join("\n") + "\n"
+}
+
+fn answer(stacks: &Vec<VecDeque<char>>) -> String {
+    let mut answer = "".to_string();
+    for stack in stacks {
+        answer.push(stack[stack.len() - 1]);
+    }
+    answer
+}
+
+pub fn parse_input(input: &str) -> (Vec<VecDeque<char>>, Vec<Move>) {
+    let mut stacks: Vec<VecDeque<char>> = Vec::new();
+    let mut lines = input.trim_end().split('\n');
+    'outer: loop {
+        let mut chars = lines.next().unwrap().chars();
+        let mut i = 0;
+        loop {
+            let skip = 1 + (if i > 0 { 2 } else { 0 });
+            let c = (&mut chars).skip(skip).take(1).next();
+            if c.is_none() {
+                break;
+            }
+            let c = c.unwrap();
+            if c.is_ascii_digit() {
+                break 'outer;
+            }
+            if stacks.len() == i {
+                stacks.push(VecDeque::new());
+            }
+            if c != ' ' {
+                stacks[i].push_front(c);
+            }
+            i += 1;
+        }
+    }
+    let mut commands: Vec<Move> = Vec::new();
+    for line in lines.skip(1) {
+        let mut s = line.split_whitespace();
+        let m = Move {
+            amount: (&mut s)
+                .skip(1)
+                .take(1)
+                .next()
+                .unwrap()
+                .parse::<usize>()
+                .unwrap(),
+            from: (&mut s)
+                .skip(1)
+                .take(1)
+                .next()
+                .unwrap()
+                .parse::<usize>()
+                .unwrap(),
+            to: (&mut s)
+                .skip(1)
+                .take(1)
+                .next()
+                .unwrap()
+                .parse::<usize>()
+                .unwrap(),
+        };
+        commands.push(m);
+    }
+    (stacks, commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let (stacks, commands) = parse_input(include_str!("../input-example"));
+        assert_eq!(stacks.len(), 3);
+        assert_eq!(commands.len(), 4);
+    }
+
+    #[test]
+    fn test_input_parse() {
+        let (stacks, _) = parse_input(include_str!("../input"));
+        assert_eq!(stacks.len(), 9);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, "CMZ");
+        assert_eq!(part2_answer, "MCD");
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 2);
+        assert_eq!(
+            steps,
+            [
+                "move 1 from 2 to 1: D one by one, stacks are now 1: ZND, 2: MC, 3: P",
+                "move 3 from 1 to 3: DNZ one by one, stacks are now 1: -, 2: MC, 3: PDNZ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drawing() {
+        let example = include_str!("../input-example");
+        snapshot::assert_snapshot!("example-start", drawing(example, 0));
+        snapshot::assert_snapshot!("example-part1", drawing(example, usize::MAX));
+        let input = include_str!("../input");
+        snapshot::assert_snapshot!("input-part1", drawing(input, usize::MAX));
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, _part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, "CNSZFDVLJ");
+    }
+}
@@ -0,0 +1,91 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    distinct_chars_offset(input.trim(), 4)
+}
+
+pub fn part2(input: &str) -> u32 {
+    distinct_chars_offset(input.trim(), 14)
+}
+
+// Narrates part 1 character by character, for at most `steps` characters after the first four.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let input = input.trim();
+    let mut narration = Vec::new();
+    for i in (4..=input.len()).take(steps) {
+        let window = &input[i - 4..i];
+        match window.chars().find(|&c| window.matches(c).count() > 1) {
+            Some(c) => narration.push(format!("after {} characters: {} repeats {}", i, window, c)),
+            None => {
+                narration.push(format!(
+                    "after {} characters: {} are all different, the start-of-packet marker",
+                    i, window
+                ));
+                break;
+            }
+        }
+    }
+    narration
+}
+
+pub fn distinct_chars_offset(input: &str, n: usize) -> u32 {
+    for i in n..input.len() {
+        let mut set = BTreeSet::new();
+        let mut chars = input.chars().skip(i - n);
+        for _ in 0..n {
+            let c = chars.next().unwrap();
+            set.insert(c);
+        }
+        if set.len() == n {
+            return i as u32;
+        }
+    }
+    panic!("offset not found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_answer() {
+        assert_eq!((7, 19), run("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        assert_eq!((5, 23), run("bvwbjplbgvbhsrlpgdmjqwftvncz"));
+        assert_eq!((6, 23), run("nppdvjthqldpwncqszvftbrmjlhg"));
+        assert_eq!((10, 29), run("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"));
+        assert_eq!((11, 26), run("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"));
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 10);
+        assert_eq!(
+            steps,
+            [
+                "after 4 characters: mjqj repeats j",
+                "after 5 characters: jqjp repeats j",
+                "after 6 characters: qjpq repeats q",
+                "after 7 characters: jpqm are all different, the start-of-packet marker",
+            ]
+        );
+        assert_eq!(explain("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 1).len(), 1);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 1093);
+        assert_eq!(part2_answer, 3534);
+    }
+}
@@ -0,0 +1,146 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    let fs = parse_input(input);
+    fs.values().filter(|&&size| size <= 100000).sum()
+}
+
+pub fn part2(input: &str) -> usize {
+    let fs = parse_input(input);
+
+    let total = 70000000usize;
+    let free = total - fs.get("/").unwrap();
+    let req = 30000000 - free;
+
+    let mut sizes: Vec<usize> = fs.values().copied().collect();
+    sizes.sort();
+    *sizes.iter().find(|&&size| size >= req).unwrap()
+}
+
+// Narrates part 1 line by line, for at most `steps` lines of terminal output, following the
+// current directory and which directories each file adds to.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut cwd: Vec<&str> = Vec::new();
+    let mut narration = Vec::new();
+    for line in input.trim_end().split('\n').take(steps) {
+        let s: Vec<&str> = line.split_whitespace().collect();
+        let step = match s[..] {
+            ["$", "cd", ".."] => {
+                cwd.pop();
+                format!("back up to {}", path(&cwd))
+            }
+            ["$", "cd", dir] => {
+                cd(&mut cwd, dir);
+                format!("into {}", path(&cwd))
+            }
+            ["$", "ls"] => format!("list {}", path(&cwd)),
+            ["dir", dir] => format!("{} has a directory {}", path(&cwd), dir),
+            [size, name] => {
+                let dirs: Vec<String> = (0..=cwd.len()).rev().map(|d| path(&cwd[..d])).collect();
+                format!("{} adds {} to {}", name, size, dirs.join(", "))
+            }
+            _ => panic!("unknown line: {}", line),
+        };
+        narration.push(format!("{}: {}", line, step));
+    }
+    narration
+}
+
+// Directories are keyed by their absolute path, e.g. "/" or "/a/e".
+pub fn parse_input(input: &str) -> BTreeMap<String, usize> {
+    let mut dirs: BTreeMap<String, usize> = BTreeMap::new();
+    let mut cwd: Vec<&str> = Vec::new();
+
+    for line in input.trim_end().split('\n') {
+        let mut s = line.split_whitespace();
+        let first = s.next().unwrap();
+        match first {
+            "$" => {
+                let second = s.next().unwrap();
+                match second {
+                    "cd" => {
+                        let third = s.next().unwrap();
+                        if third == ".." {
+                            cwd.pop();
+                        } else {
+                            cd(&mut cwd, third);
+                        }
+                    }
+                    "ls" => (),
+                    _ => {
+                        panic!("unknown command {} {}", first, second);
+                    }
+                }
+            }
+            "dir" => (),
+            _ => {
+                let _name = s.next().unwrap();
+                let size = first.parse::<usize>().unwrap();
+                for depth in 0..=cwd.len() {
+                    *dirs.entry(path(&cwd[..depth])).or_insert(0) += size;
+                }
+            }
+        }
+    }
+
+    dirs
+}
+
+fn cd<'a>(cwd: &mut Vec<&'a str>, dir: &'a str) {
+    if dir == "/" {
+        cwd.clear();
+    } else {
+        cwd.push(dir);
+    }
+}
+
+fn path(dirs: &[&str]) -> String {
+    format!("/{}", dirs.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let fs = parse_input(include_str!("../input-example"));
+        assert_eq!(fs.len(), 4);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 95437);
+        assert_eq!(part2_answer, 24933642);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 10);
+        assert_eq!(steps[0], "$ cd /: into /");
+        assert_eq!(steps[2], "dir a: / has a directory a");
+        assert_eq!(steps[3], "14848514 b.txt: b.txt adds 14848514 to /");
+        assert_eq!(steps[6], "$ cd a: into /a");
+        assert_eq!(steps[9], "29116 f: f adds 29116 to /a, /");
+        assert_eq!(steps.len(), 10);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 1443806);
+        assert_eq!(part2_answer, 942298);
+    }
+}
@@ -0,0 +1,237 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp;
+
+pub const N: usize = 100;
+pub type Tree = u8;
+#[derive(Debug)]
+pub struct Grid {
+    pub trees: [[Tree; N]; N],
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Grid {
+    pub fn parse_input(input: &str) -> Grid {
+        let mut grid: Grid = Grid {
+            trees: [[0; N]; N],
+            width: 0,
+            height: 0,
+        };
+        let input = input.trim_end().split('\n');
+        for (y, line) in input.enumerate() {
+            for (x, d) in line
+                .chars()
+                .map(|c| c.to_digit(10).unwrap() as u8)
+                .enumerate()
+            {
+                grid.width = cmp::max(x, grid.width);
+                grid.height = cmp::max(y, grid.height);
+                grid.trees[y][x] = d;
+            }
+        }
+        grid
+    }
+
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        self.visible_from(x, y).contains(&true)
+    }
+
+    // whether the tree can be seen from the left, right, top and bottom edge
+    pub fn visible_from(&self, x: usize, y: usize) -> [bool; 4] {
+        let h = self.trees[y][x];
+        [
+            (0..x).all(|a| self.trees[y][a] < h),
+            (x + 1..=self.width).all(|a| self.trees[y][a] < h),
+            (0..y).all(|b| self.trees[b][x] < h),
+            (y + 1..=self.height).all(|b| self.trees[b][x] < h),
+        ]
+    }
+
+    // The grid with the trees visible from outside it shown by their height and the hidden ones
+    // as dots.
+    pub fn visibility_map(&self) -> String {
+        let mut map = String::new();
+        for y in 0..=self.height {
+            for x in 0..=self.width {
+                if self.is_visible(x, y) {
+                    map.push((b'0' + self.trees[y][x]) as char);
+                } else {
+                    map.push('.');
+                }
+            }
+            map.push('\n');
+        }
+        map
+    }
+
+    pub fn count_visible(&self) -> usize {
+        let mut n: usize = 0;
+        for x in 0..=self.width {
+            for y in 0..=self.height {
+                if self.is_visible(x, y) {
+                    n += 1;
+                }
+            }
+        }
+        n
+    }
+
+    pub fn scenic_score(&self, x: usize, y: usize) -> u32 {
+        let h = self.trees[y][x];
+
+        // look left
+        let mut score_left: u32 = 0;
+        for a in (0..x).rev() {
+            score_left += 1;
+            if self.trees[y][a] >= h {
+                break;
+            }
+        }
+        // look right
+        let mut score_right: u32 = 0;
+        for a in x + 1..=self.width {
+            score_right += 1;
+            if self.trees[y][a] >= h {
+                break;
+            }
+        }
+        // look up
+        let mut score_up: u32 = 0;
+        for b in (0..y).rev() {
+            score_up += 1;
+            if self.trees[b][x] >= h {
+                break;
+            }
+        }
+        // look down
+        let mut score_down: u32 = 0;
+        for b in y + 1..=self.height {
+            score_down += 1;
+            if self.trees[b][x] >= h {
+                break;
+            }
+        }
+        score_up * score_left * score_right * score_down
+    }
+
+    pub fn max_scenic_score(&self) -> u32 {
+        let mut score: u32 = 0;
+        for x in 0..=self.width {
+            for y in 0..=self.height {
+                score = cmp::max(score, self.scenic_score(x, y));
+            }
+        }
+        score
+    }
+}
+
+// Narrates part 1 for the interior trees row by row, for at most `steps` trees; the trees around
+// the edge are all visible.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    const EDGES: [&str; 4] = ["left", "right", "top", "bottom"];
+    let grid = Grid::parse_input(input);
+    let interior = (1..grid.height).flat_map(|y| (1..grid.width).map(move |x| (x, y)));
+    interior
+        .take(steps)
+        .map(|(x, y)| {
+            let edges: Vec<&str> = EDGES
+                .iter()
+                .zip(grid.visible_from(x, y))
+                .filter(|(_, visible)| *visible)
+                .map(|(edge, _)| *edge)
+                .collect();
+            let verdict = if edges.is_empty() {
+                "hidden from every edge".to_string()
+            } else {
+                format!("visible from the {}", edges.join(", "))
+            };
+            format!(
+                "tree at x={}, y={} with height {}: {}",
+                x, y, grid.trees[y][x], verdict
+            )
+        })
+        .collect()
+}
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    Grid::parse_input(input).count_visible() as u32
+}
+
+pub fn part2(input: &str) -> u32 {
+    Grid::parse_input(input).max_scenic_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let grid = Grid::parse_input(include_str!("../input-example"));
+        // off by one
+        assert_eq!(grid.width, 4);
+        assert_eq!(grid.height, 4);
+    }
+
+    #[test]
+    fn test_example_visibility() {
+        let grid = Grid::parse_input(include_str!("../input-example"));
+        assert!(grid.is_visible(0, 0));
+        assert!(grid.is_visible(4, 0));
+        assert!(!grid.is_visible(1, 3));
+        assert!(grid.is_visible(2, 3));
+        assert!(!grid.is_visible(3, 3));
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let grid = Grid::parse_input(include_str!("../input-example"));
+        assert_eq!(grid.scenic_score(2, 1), 4);
+        assert_eq!(grid.scenic_score(2, 3), 8);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 21);
+        assert_eq!(part2_answer, 8);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 3);
+        assert_eq!(
+            steps,
+            [
+                "tree at x=1, y=1 with height 5: visible from the left, top",
+                "tree at x=2, y=1 with height 5: visible from the right, top",
+                "tree at x=3, y=1 with height 1: hidden from every edge",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visibility_map() {
+        let example = Grid::parse_input(include_str!("../input-example"));
+        snapshot::assert_snapshot!("example-visibility", example.visibility_map());
+        let input = Grid::parse_input(include_str!("../input"));
+        snapshot::assert_snapshot!("input-visibility", input.visibility_map());
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 1705);
+        assert_eq!(part2_answer, 371200);
+    }
+}
@@ -0,0 +1,176 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::slice::Iter;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    Rope::simulate(parse_input(input).iter(), 2)
+}
+
+pub fn part2(input: &str) -> usize {
+    Rope::simulate(parse_input(input).iter(), 10)
+}
+
+// Narrates part 1 motion by motion, for at most `steps` motions. Positions are given with y
+// pointing up, as in the puzzle.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut rope = Rope::new(2);
+    let mut narration = Vec::new();
+    for &(direction, n) in parse_input(input).iter().take(steps) {
+        rope.simulate_step(direction, n);
+        narration.push(format!(
+            "{} {}: head moves to ({}, {}), tail follows to ({}, {}), {} positions visited",
+            direction,
+            n,
+            rope.kx[0],
+            -rope.ky[0],
+            rope.kx[1],
+            -rope.ky[1],
+            rope.count_visited()
+        ));
+    }
+    narration
+}
+
+#[derive(Debug)]
+pub struct Rope {
+    visited: BTreeSet<(isize, isize)>,
+    kx: Vec<isize>,
+    ky: Vec<isize>,
+}
+
+impl Rope {
+    pub fn simulate(motions: Iter<(char, u8)>, knots: usize) -> usize {
+        let mut rope = Rope::new(knots);
+        for motion in motions {
+            rope.simulate_step(motion.0, motion.1);
+        }
+        rope.count_visited()
+    }
+
+    fn new(knots: usize) -> Self {
+        Rope {
+            visited: BTreeSet::new(),
+            kx: vec![0; knots],
+            ky: vec![0; knots],
+        }
+    }
+
+    fn simulate_step(&mut self, direction: char, steps: u8) {
+        for _ in 0..steps {
+            match direction {
+                'L' => self.kx[0] -= 1,
+                'R' => self.kx[0] += 1,
+                'U' => self.ky[0] -= 1,
+                'D' => self.ky[0] += 1,
+                _ => panic!("unknown direction: {}", direction),
+            };
+            self.update_knots();
+        }
+    }
+
+    fn update_knots(&mut self) {
+        for i in 1..self.kx.len() {
+            let touching = isize::abs(self.kx[i - 1] - self.kx[i]) <= 1
+                && isize::abs(self.ky[i - 1] - self.ky[i]) <= 1;
+
+            if touching {
+                continue;
+            }
+
+            match self.kx[i - 1].cmp(&self.kx[i]) {
+                Ordering::Greater => self.kx[i] += 1,
+                Ordering::Less => self.kx[i] -= 1,
+                Ordering::Equal => (),
+            }
+
+            match self.ky[i - 1].cmp(&self.ky[i]) {
+                Ordering::Greater => self.ky[i] += 1,
+                Ordering::Less => self.ky[i] -= 1,
+                Ordering::Equal => (),
+            }
+        }
+
+        self.update_visited();
+    }
+
+    fn update_visited(&mut self) {
+        self.visited
+            .insert((self.ky[self.ky.len() - 1], self.kx[self.kx.len() - 1]));
+    }
+
+    fn count_visited(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<(char, u8)> {
+    let mut ret = Vec::new();
+    for line in input.trim_end().split('\n') {
+        let mut s = line.split_whitespace();
+        ret.push((
+            s.next().unwrap().parse::<char>().unwrap(),
+            s.next().unwrap().parse::<u8>().unwrap(),
+        ))
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example1_parse() {
+        let lines = parse_input(include_str!("../input-example1"));
+        assert_eq!(lines.len(), 8);
+    }
+
+    #[test]
+    fn test_example2_parse() {
+        let lines = parse_input(include_str!("../input-example2"));
+        assert_eq!(lines.len(), 8);
+    }
+
+    #[test]
+    fn test_example1_answer() {
+        let (part1_answer, _) = run(include_str!("../input-example1"));
+        assert_eq!(part1_answer, 13);
+    }
+
+    #[test]
+    fn test_example2_answer() {
+        let (_, part2_answer) = run(include_str!("../input-example2"));
+        assert_eq!(part2_answer, 36);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example1"), 2);
+        assert_eq!(
+            steps,
+            [
+                "R 4: head moves to (4, 0), tail follows to (3, 0), 4 positions visited",
+                "U 4: head moves to (4, 4), tail follows to (4, 3), 7 positions visited",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 6494);
+        assert_eq!(part2_answer, 2691);
+    }
+}
@@ -0,0 +1,171 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub fn run(input: &str) -> (i32, String) {
+    simulate(input)
+}
+
+pub fn part1(input: &str) -> i32 {
+    simulate(input).0
+}
+
+pub fn part2(input: &str) -> String {
+    simulate(input).1
+}
+
+// both parts come out of the same pass over the program
+fn simulate(input: &str) -> (i32, String) {
+    let mut part1_answer: i32 = 0;
+    let mut part2_answer = String::new();
+
+    let lines = parse_input(input);
+    let mut cycles: i32 = 0;
+    let mut x: i32 = 1;
+
+    for line in lines {
+        let mut s = line.split_whitespace();
+        let cmd = s.next().unwrap();
+        match cmd {
+            "noop" => {
+                cycles += 1;
+                update_answers(&mut part1_answer, &mut part2_answer, &mut cycles, &mut x);
+            }
+            "addx" => {
+                let dx = s.next().unwrap().parse::<i32>().unwrap();
+                cycles += 1;
+                update_answers(&mut part1_answer, &mut part2_answer, &mut cycles, &mut x);
+                cycles += 1;
+                update_answers(&mut part1_answer, &mut part2_answer, &mut cycles, &mut x);
+                x += dx;
+            }
+            _ => {
+                panic!("cmd: {}", cmd);
+            }
+        }
+    }
+
+    (part1_answer, part2_answer)
+}
+
+// Narrates both parts instruction by instruction, for at most `steps` instructions: the value of
+// X while the instruction runs, the pixels the CRT draws meanwhile and any signal strengths
+// sampled.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut narration = Vec::new();
+    let mut cycles: i32 = 0;
+    let mut x: i32 = 1;
+    for line in parse_input(input).into_iter().take(steps) {
+        let mut s = line.split_whitespace();
+        let (duration, dx) = match s.next().unwrap() {
+            "noop" => (1, 0),
+            "addx" => (2, s.next().unwrap().parse::<i32>().unwrap()),
+            cmd => panic!("cmd: {}", cmd),
+        };
+        let first = cycles + 1;
+        let mut pixels = String::new();
+        let mut signals = String::new();
+        for _ in 0..duration {
+            cycles += 1;
+            let pos = (cycles - 1) % 40;
+            pixels.push(if (pos - x).abs() <= 1 { '#' } else { '.' });
+            if cycles == 20 || (cycles + 20) % 40 == 0 {
+                signals += &format!(
+                    ", signal strength in cycle {} is {} * {} = {}",
+                    cycles,
+                    cycles,
+                    x,
+                    cycles * x
+                );
+            }
+        }
+        let during = if duration == 1 {
+            format!("cycle {}", first)
+        } else {
+            format!("cycles {}-{}", first, cycles)
+        };
+        let mut step = format!(
+            "{}, {}: X is {}, the CRT draws {}{}",
+            during, line, x, pixels, signals
+        );
+        if dx != 0 {
+            x += dx;
+            step += &format!(", then X becomes {}", x);
+        }
+        narration.push(step);
+    }
+    narration
+}
+
+fn update_answers(
+    part1_answer: &mut i32,
+    part2_answer: &mut String,
+    cycles: &mut i32,
+    x: &mut i32,
+) {
+    if *cycles == 20 || (*cycles + 20) % 40 == 0 {
+        *part1_answer += *cycles * *x;
+    }
+
+    let pos = (*cycles - 1) % 40;
+    if pos >= *x - 1 && pos <= *x + 1 {
+        *part2_answer += "#";
+    } else {
+        *part2_answer += ".";
+    }
+    if *cycles % 40 == 0 {
+        *part2_answer += "\n";
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.trim_end().split('\n').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let lines = parse_input(include_str!("../input-example"));
+        assert_eq!(lines.len(), 146);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 13140);
+        assert_eq!(part2_answer, include_str!("../input-example-answer-part2"));
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain("noop\naddx 3\naddx -5\n", 5);
+        assert_eq!(
+            steps,
+            [
+                "cycle 1, noop: X is 1, the CRT draws #",
+                "cycles 2-3, addx 3: X is 1, the CRT draws ##, then X becomes 4",
+                "cycles 4-5, addx -5: X is 4, the CRT draws ##, then X becomes -1",
+            ]
+        );
+        let steps = explain(include_str!("../input-example"), 11);
+        assert_eq!(
+            steps[10],
+            "cycles 20-21, addx -1: X is 21, the CRT draws .#, signal strength in cycle 20 is \
+             20 * 21 = 420, then X becomes 20"
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 16480);
+        assert_eq!(part2_answer, include_str!("../input-answer-part2"));
+    }
+}
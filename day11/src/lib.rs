@@ -0,0 +1,245 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+pub fn run(input: &str) -> (u64, u64) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u64 {
+    shenanigans(parse_input(input), 20, |wl| wl / 3)
+}
+
+pub fn part2(input: &str) -> u64 {
+    let monkeys = parse_input(input);
+    let product: u64 = monkeys.iter().map(|m| m.test).product();
+    shenanigans(monkeys, 10000, |wl| wl % product)
+}
+
+pub fn shenanigans(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    simplify_wl: impl Fn(u64) -> u64,
+) -> u64 {
+    let mut inspected = vec![0u64; monkeys.len()];
+
+    for _round in 1..=rounds {
+        #[allow(clippy::needless_range_loop)]
+        for i in 0..monkeys.len() {
+            let mut items = {
+                let monkey = monkeys.get_mut(i).unwrap();
+                mem::take(&mut monkey.items)
+            };
+            let monkey = monkeys.get(i).unwrap().clone();
+            while let Some(item) = items.pop_front() {
+                inspected[i] += 1;
+                let wl = simplify_wl(monkey.operation.apply(item));
+                monkeys
+                    .get_mut(monkey.target(wl))
+                    .unwrap()
+                    .items
+                    .push_back(wl);
+            }
+        }
+    }
+
+    inspected.sort();
+    inspected.reverse();
+    inspected[0] * inspected[1]
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub operation: Operation,
+    pub test: u64,
+    pub test_true: usize,
+    pub test_false: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Squared,
+    Times(u64),
+    Plus(u64),
+}
+
+impl Monkey {
+    // the monkey an item with the given worry level is thrown to
+    pub fn target(&self, wl: u64) -> usize {
+        if wl.is_multiple_of(self.test) {
+            self.test_true
+        } else {
+            self.test_false
+        }
+    }
+}
+
+impl Operation {
+    pub fn apply(&self, wl: u64) -> u64 {
+        match self {
+            Operation::Squared => wl * wl,
+            Operation::Times(x) => wl * x,
+            Operation::Plus(x) => wl + x,
+        }
+    }
+}
+
+// Narrates part 1 inspection by inspection, for at most `steps` inspections, in the words of the
+// puzzle's walkthrough of the first round.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut monkeys = parse_input(input);
+    let mut narration = Vec::new();
+    for round in 1..=20 {
+        for i in 0..monkeys.len() {
+            let items = mem::take(&mut monkeys[i].items);
+            let monkey = monkeys[i].clone();
+            for item in items {
+                if narration.len() == steps {
+                    return narration;
+                }
+                let wl = monkey.operation.apply(item);
+                let operation = match monkey.operation {
+                    Operation::Squared => "multiplied by itself".to_string(),
+                    Operation::Times(x) => format!("multiplied by {}", x),
+                    Operation::Plus(x) => format!("increased by {}", x),
+                };
+                let bored = wl / 3;
+                let target = monkey.target(bored);
+                let divisible = if bored.is_multiple_of(monkey.test) {
+                    ""
+                } else {
+                    "not "
+                };
+                narration.push(format!(
+                    "round {}: monkey {} inspects an item with a worry level of {}, {} to {}, \
+                     divided by 3 to {}, {}divisible by {}, thrown to monkey {}",
+                    round, i, item, operation, wl, bored, divisible, monkey.test, target
+                ));
+                monkeys[target].items.push_back(bored);
+            }
+        }
+    }
+    narration
+}
+
+pub fn parse_input(input: &str) -> Vec<Monkey> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut lines = input.trim_end().split('\n');
+    loop {
+        lines.next().unwrap();
+        let s = lines
+            .next()
+            .unwrap()
+            .trim()
+            .strip_prefix("Starting items: ")
+            .unwrap();
+        let starting_items: VecDeque<u64> = s
+            .split(", ")
+            .map(|line_str| line_str.parse::<u64>().unwrap())
+            .collect();
+        let s = lines
+            .next()
+            .unwrap()
+            .trim()
+            .strip_prefix("Operation: new = old ")
+            .unwrap();
+        let mut p = s.split_whitespace();
+        let op = p.next().unwrap();
+        let scalar = p.next().unwrap();
+        let operation = match op {
+            "*" => match scalar {
+                "old" => Operation::Squared,
+                _ => Operation::Times(scalar.parse::<u64>().unwrap()),
+            },
+            "+" => Operation::Plus(scalar.parse::<u64>().unwrap()),
+            _ => panic!("unknown op: {}", op),
+        };
+        let test = lines
+            .next()
+            .unwrap()
+            .trim()
+            .strip_prefix("Test: divisible by ")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        let test_true = lines
+            .next()
+            .unwrap()
+            .trim()
+            .strip_prefix("If true: throw to monkey ")
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        let test_false = lines
+            .next()
+            .unwrap()
+            .trim()
+            .strip_prefix("If false: throw to monkey ")
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        let monkey = Monkey {
+            items: starting_items,
+            operation,
+            test,
+            test_true,
+            test_false,
+        };
+        monkeys.push(monkey);
+        if lines.next().is_none() {
+            break;
+        }
+    }
+    monkeys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let lines = parse_input(include_str!("../input-example"));
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 10605);
+        assert_eq!(part2_answer, 2713310158);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 3);
+        assert_eq!(
+            steps,
+            [
+                "round 1: monkey 0 inspects an item with a worry level of 79, multiplied by 19 to \
+                 1501, divided by 3 to 500, not divisible by 23, thrown to monkey 3",
+                "round 1: monkey 0 inspects an item with a worry level of 98, multiplied by 19 to \
+                 1862, divided by 3 to 620, not divisible by 23, thrown to monkey 3",
+                "round 1: monkey 1 inspects an item with a worry level of 54, increased by 6 to \
+                 60, divided by 3 to 20, not divisible by 19, thrown to monkey 0",
+            ]
+        );
+        // every inspection of all 20 rounds: 101 + 95 + 7 + 105
+        assert_eq!(explain(include_str!("../input-example"), 1000).len(), 308);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 62491);
+        assert_eq!(part2_answer, 17408399184);
+    }
+}
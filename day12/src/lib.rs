@@ -0,0 +1,392 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+use core::cmp::Ordering;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    let em = ElevationMap::parse_input(input);
+    em.find_path(em.start)
+}
+
+pub fn part2(input: &str) -> usize {
+    let em = ElevationMap::parse_input(input);
+    let mut distances: Vec<usize> = Vec::new();
+    for y in 0..=em.max_y {
+        for x in 0..=em.max_x {
+            if em.elevations[y][x] == 0 {
+                let distance = em.find_path((x, y));
+                distances.push(distance);
+            }
+        }
+    }
+    distances.sort();
+    distances[0]
+}
+
+// Narrates part 1 move by move along a shortest path, for at most `steps` moves.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let em = ElevationMap::parse_input(input);
+    let path = em.shortest_path();
+    if path.is_empty() {
+        return vec!["the end cannot be reached".to_string()];
+    }
+    path.windows(2)
+        .take(steps)
+        .enumerate()
+        .map(|(i, step)| {
+            let (x, y) = step[1];
+            let (direction, _) = direction(step[0], step[1]);
+            let elevation = (b'a' + em.elevations[y][x] as u8) as char;
+            let end = if (x, y) == em.end { ", the end" } else { "" };
+            format!(
+                "move {}: {} to ({}, {}) at elevation {}{}",
+                i + 1,
+                direction,
+                x,
+                y,
+                elevation,
+                end
+            )
+        })
+        .collect()
+}
+
+// Draws a shortest path for part 1 the way the puzzle does: every square on it has an arrow
+// pointing to the next one, the end is marked with an E and all other squares are dots.
+pub fn route(input: &str) -> String {
+    let em = ElevationMap::parse_input(input);
+    let mut map = vec![vec!['.'; em.max_x + 1]; em.max_y + 1];
+    let path = em.shortest_path();
+    for step in path.windows(2) {
+        let (x, y) = step[0];
+        map[y][x] = direction(step[0], step[1]).1;
+    }
+    map[em.end.1][em.end.0] = 'E';
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// The direction of a single move, in words and as an arrow.
+fn direction((x0, y0): (usize, usize), (x, y): (usize, usize)) -> (&'static str, char) {
+    match (x.cmp(&x0), y.cmp(&y0)) {
+        (Ordering::Less, _) => ("left", '<'),
+        (Ordering::Greater, _) => ("right", '>'),
+        (_, Ordering::Less) => ("up", '^'),
+        _ => ("down", 'v'),
+    }
+}
+
+pub fn get_elevation(elevation: char) -> Elevation {
+    if elevation.is_lowercase() {
+        (elevation as Elevation) - 97
+    } else {
+        match elevation {
+            'S' => 0 as Elevation,
+            'E' => 25 as Elevation,
+            _ => panic!("unknown elevation: {}", elevation),
+        }
+    }
+}
+
+pub const N: usize = 80;
+pub type Elevation = i8;
+
+#[derive(Debug)]
+pub struct ElevationMap {
+    pub elevations: [[Elevation; N]; N],
+    pub max_x: usize,
+    pub max_y: usize,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+#[derive(Default, Debug, Copy, Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+    elevation: Elevation,
+}
+
+#[derive(Default, Debug, Copy, Clone)]
+struct Visit<V> {
+    pos: V,
+    distance: usize,
+}
+
+impl Eq for Position {}
+impl PartialEq<Self> for Position {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<V> Ord for Visit<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl<V> PartialOrd for Visit<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> PartialEq for Visit<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance.eq(&other.distance)
+    }
+}
+
+impl<V> Eq for Visit<V> {}
+
+impl Ord for Position {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.x, self.y).cmp(&(other.x, other.y))
+    }
+}
+
+impl PartialOrd<Self> for Position {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ElevationMap {
+    pub fn parse_input(input: &str) -> ElevationMap {
+        let mut em: ElevationMap = ElevationMap {
+            elevations: [[0; N]; N],
+            max_x: 0,
+            max_y: 0,
+            start: (0, 0),
+            end: (0, 0),
+        };
+        let input = input.trim_end().split('\n');
+        for (y, line) in input.enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    'S' => {
+                        em.start = (x, y);
+                    }
+                    'E' => {
+                        em.end = (x, y);
+                    }
+                    _ => (),
+                }
+                em.max_x = cmp::max(x, em.max_x);
+                em.max_y = cmp::max(y, em.max_y);
+                em.elevations[y][x] = get_elevation(c);
+            }
+        }
+        em
+    }
+
+    pub fn find_path(&self, start: (usize, usize)) -> usize {
+        let end = Position {
+            x: self.end.0,
+            y: self.end.1,
+            elevation: 0,
+        };
+        self.distances(start).get(&end).map_or(usize::MAX, |d| *d)
+    }
+
+    // adventofcode2021 day15
+    fn distances(&self, start: (usize, usize)) -> BTreeMap<Position, usize> {
+        let mut distances = BTreeMap::new();
+        let mut visited = BTreeSet::new();
+        let mut to_visit: BinaryHeap<Visit<Position>> = BinaryHeap::new();
+
+        // start
+        to_visit.push(Visit {
+            pos: Position {
+                x: start.0,
+                y: start.1,
+                elevation: self.elevations[start.1][start.0],
+            },
+            distance: 0,
+        });
+
+        // the counters for the trace event are only kept while tracing
+        let tracing = trace::enabled();
+        let mut max_heap = to_visit.len();
+        while let Some(Visit { pos, distance }) = to_visit.pop() {
+            // TODO: terminate early if we are at the end position
+
+            if !visited.insert(pos) {
+                continue;
+            }
+
+            let adj = self.get_adjacent(pos);
+            for neighbour in adj {
+                let new_distance = distance + 1;
+
+                let is_shorter = distances
+                    .get(&neighbour)
+                    .is_none_or(|&current| new_distance < current);
+
+                if is_shorter {
+                    distances.insert(neighbour, new_distance);
+                    to_visit.push(Visit {
+                        pos: neighbour,
+                        distance: new_distance,
+                    })
+                }
+            }
+            if tracing {
+                max_heap = max_heap.max(to_visit.len());
+            }
+        }
+
+        trace::event!(
+            "day12.find_path",
+            x = start.0,
+            y = start.1,
+            visited = visited.len(),
+            max_heap = max_heap,
+        );
+
+        distances
+    }
+
+    // A shortest path from start to end, found by walking back from the end to ever closer
+    // squares it can be reached from.
+    fn shortest_path(&self) -> Vec<(usize, usize)> {
+        let distances = self.distances(self.start);
+        let distance = |(x, y): (usize, usize)| {
+            let pos = Position { x, y, elevation: 0 };
+            match distances.get(&pos) {
+                _ if (x, y) == self.start => Some(0),
+                d => d.copied(),
+            }
+        };
+        let mut path = vec![self.end];
+        let Some(mut d) = distance(self.end) else {
+            return Vec::new();
+        };
+        while d > 0 {
+            let (x, y) = *path.last().unwrap();
+            let elevation = self.elevations[y][x];
+            let previous = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ]
+            .into_iter()
+            .find(|&(px, py)| {
+                px <= self.max_x
+                    && py <= self.max_y
+                    && distance((px, py)) == Some(d - 1)
+                    && elevation - self.elevations[py][px] <= 1
+            })
+            .unwrap();
+            path.push(previous);
+            d -= 1;
+        }
+        path.reverse();
+        path
+    }
+
+    // "you can move exactly one square up, down, left, or right" / "at most one higher"
+    fn get_adjacent(&self, pos: Position) -> Vec<Position> {
+        let mut ret = Vec::new();
+        let x = pos.x;
+        let y = pos.y;
+        if x != 0 {
+            // left
+            let x = x - 1;
+            let elevation = self.elevations[y][x] as Elevation;
+            if elevation - pos.elevation <= 1 {
+                ret.push(Position { x, y, elevation })
+            }
+        }
+        if x != self.max_x {
+            // right
+            let x = x + 1;
+            // let y = y;
+            let elevation = self.elevations[y][x] as Elevation;
+            if elevation - pos.elevation <= 1 {
+                ret.push(Position { x, y, elevation })
+            }
+        }
+        if y != 0 {
+            // up
+            let y = y - 1;
+            let elevation = self.elevations[y][x] as Elevation;
+            if elevation - pos.elevation <= 1 {
+                ret.push(Position { x, y, elevation })
+            }
+        }
+        if y != self.max_y {
+            // down
+            let y = y + 1;
+            let elevation = self.elevations[y][x] as Elevation;
+            if elevation - pos.elevation <= 1 {
+                ret.push(Position { x, y, elevation })
+            }
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elevation() {
+        assert_eq!(get_elevation('b'), 1);
+        assert_eq!(get_elevation('d'), 3);
+        assert_eq!(get_elevation('S'), 0);
+        assert_eq!(get_elevation('E'), 25);
+    }
+
+    #[test]
+    fn test_example_parse() {
+        let em = ElevationMap::parse_input(include_str!("../input-example"));
+        assert_eq!(em.max_x, 7);
+        assert_eq!(em.max_y, 4);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 31);
+        assert_eq!(part2_answer, 29);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 100);
+        assert_eq!(steps.len(), 31);
+        assert!(steps[0].starts_with("move 1: "), "{}", steps[0]);
+        assert_eq!(
+            steps[30],
+            "move 31: right to (5, 2) at elevation z, the end"
+        );
+    }
+
+    #[test]
+    fn test_route() {
+        snapshot::assert_snapshot!("example-route", route(include_str!("../input-example")));
+        snapshot::assert_snapshot!("input-route", route(include_str!("../input")));
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 391);
+        assert_eq!(part2_answer, 386);
+    }
+}
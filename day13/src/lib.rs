@@ -0,0 +1,302 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use Packet::{Integer, List};
+
+#[derive(Debug, Clone, Eq)]
+pub enum Packet {
+    Integer(u8),
+    List(VecDeque<Packet>),
+}
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    let mut part1_answer: usize = 0;
+    let pairs = parse_input(input);
+    for (i, pair) in pairs.into_iter().enumerate() {
+        if pair.0 < pair.1 {
+            part1_answer += i + 1;
+        }
+    }
+    part1_answer
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut packets: Vec<Packet> = parse_input(input)
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+        .collect();
+    let d1 = read_packet("[[2]]");
+    let d2 = read_packet("[[6]]");
+    packets.push(d1.clone());
+    packets.push(d2.clone());
+    packets.sort();
+    let d1i = packets.iter().position(|p| *p == d1).unwrap() + 1;
+    let d2i = packets.iter().position(|p| *p == d2).unwrap() + 1;
+    d1i * d2i
+}
+
+// Narrates part 1 the way the puzzle's walkthrough does, comparison by comparison, for at most
+// `steps` lines.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut narration = Vec::new();
+    for (i, (left, right)) in parse_input(input).iter().enumerate() {
+        if narration.len() >= steps {
+            break;
+        }
+        narration.push(format!("== Pair {} ==", i + 1));
+        narrate(left, right, 0, &mut narration);
+    }
+    narration.truncate(steps);
+    narration
+}
+
+// Packet::cmp, narrated.
+fn narrate(left: &Packet, right: &Packet, depth: usize, narration: &mut Vec<String>) -> Ordering {
+    let indent = "  ".repeat(depth);
+    narration.push(format!("{}- Compare {} vs {}", indent, left, right));
+    let (order, reason) = match (left, right) {
+        (Integer(l), Integer(r)) => (l.cmp(r), "side is smaller"),
+        (List(l), List(r)) => {
+            for (a, b) in l.iter().zip(r) {
+                let order = narrate(a, b, depth + 1, narration);
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            (l.len().cmp(&r.len()), "side ran out of items")
+        }
+        (Integer(l), List(_)) => {
+            narration.push(format!(
+                "{}  - Mixed types; convert left to [{}] and retry comparison",
+                indent, l
+            ));
+            return narrate(
+                &List(VecDeque::from([Integer(*l)])),
+                right,
+                depth + 1,
+                narration,
+            );
+        }
+        (List(_), Integer(r)) => {
+            narration.push(format!(
+                "{}  - Mixed types; convert right to [{}] and retry comparison",
+                indent, r
+            ));
+            return narrate(
+                left,
+                &List(VecDeque::from([Integer(*r)])),
+                depth + 1,
+                narration,
+            );
+        }
+    };
+    match order {
+        Ordering::Less => narration.push(format!(
+            "{}  - Left {}, so inputs are in the right order",
+            indent, reason
+        )),
+        Ordering::Greater => narration.push(format!(
+            "{}  - Right {}, so inputs are not in the right order",
+            indent, reason
+        )),
+        Ordering::Equal => {}
+    }
+    order
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Integer(v) => write!(f, "{}", v),
+            List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Integer(left), Integer(right)) => left.cmp(right),
+            (List(ref left), List(ref right)) => {
+                let mut left = left.clone();
+                let mut right = right.clone();
+                loop {
+                    return match (left.pop_front(), right.pop_front()) {
+                        (Some(a), Some(b)) => {
+                            let order = a.cmp(&b);
+                            if order == Ordering::Equal {
+                                continue;
+                            }
+                            order
+                        }
+                        (None, Some(_)) => Ordering::Less,
+                        (Some(_), None) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    };
+                }
+            }
+            (Integer(left), List(ref right)) => {
+                List(VecDeque::from([Integer(*left)])).cmp(&List(right.clone()))
+            }
+            (List(ref left), Integer(right)) => {
+                List(left.clone()).cmp(&List(VecDeque::from([Integer(*right)])))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+pub fn read_packet(raw_packet: &str) -> Packet {
+    let mut stack: VecDeque<Packet> = VecDeque::new();
+    let mut chars = raw_packet.chars().peekable();
+    while chars.peek().is_some() {
+        let c = chars.next().unwrap();
+        if c == '[' {
+            stack.push_back(List(VecDeque::new()));
+        } else if c == ']' {
+            if stack.len() > 1 {
+                let s = stack.pop_back().unwrap();
+                if let List(l) = stack.back_mut().unwrap() {
+                    l.push_back(s);
+                }
+            }
+        } else if c.is_ascii_digit() {
+            let mut s = c.to_string();
+            if chars.peek().unwrap().is_ascii_digit() {
+                s.push(chars.next().unwrap());
+            }
+            if let List(l) = stack.back_mut().unwrap() {
+                l.push_back(Integer(s.parse::<u8>().unwrap()))
+            }
+        } else if c == ',' {
+            continue;
+        } else {
+            panic!("unknown char: {}", c);
+        }
+    }
+    assert_eq!(stack.len(), 1);
+    stack.pop_back().unwrap()
+}
+
+pub fn parse_input(input: &str) -> Vec<(Packet, Packet)> {
+    let mut pairs = Vec::new();
+    for pair in input.trim_end().split("\n\n") {
+        let mut lines = pair.split_whitespace();
+        let a = read_packet(lines.next().unwrap());
+        let b = read_packet(lines.next().unwrap());
+        pairs.push((a, b))
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let pairs = parse_input(include_str!("../input-example"));
+        assert_eq!(pairs.len(), 8);
+    }
+
+    fn test_packets(packets: (&str, &str)) -> Ordering {
+        read_packet(packets.0)
+            .partial_cmp(&read_packet(packets.1))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_order() {
+        assert_eq!(test_packets(("[1,1]", "[1,1,1]")), Ordering::Less);
+        assert_eq!(test_packets(("[1,1,1]", "[1,1]")), Ordering::Greater);
+        assert_eq!(test_packets(("[]", "[]")), Ordering::Equal);
+        assert_eq!(test_packets(("[1]", "[2]")), Ordering::Less);
+        assert_eq!(test_packets(("[2]", "[1]")), Ordering::Greater);
+        assert_eq!(test_packets(("[1]", "[[2]]")), Ordering::Less);
+        assert_eq!(test_packets(("[[1]", "[2]")), Ordering::Less);
+        assert_eq!(test_packets(("[1]", "[[1],1]")), Ordering::Less);
+        assert_eq!(test_packets(("[[],4]", "[[],3]")), Ordering::Greater);
+        assert_eq!(test_packets(("[[0,0],2]", "[[0,0],1]")), Ordering::Greater);
+        assert_eq!(test_packets(("[1]", "[[1,2,3]]")), Ordering::Less);
+        assert_eq!(test_packets(("[1]", "[[0,2,3]]")), Ordering::Greater);
+        assert_eq!(test_packets(("[10]", "[2]")), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 13);
+        assert_eq!(part2_answer, 140);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 100);
+        assert_eq!(
+            steps[..6],
+            [
+                "== Pair 1 ==",
+                "- Compare [1,1,3,1,1] vs [1,1,5,1,1]",
+                "  - Compare 1 vs 1",
+                "  - Compare 1 vs 1",
+                "  - Compare 3 vs 5",
+                "    - Left side is smaller, so inputs are in the right order",
+            ]
+        );
+        let pair3 = steps.iter().position(|s| s == "== Pair 3 ==").unwrap();
+        assert_eq!(
+            steps[pair3..pair3 + 7],
+            [
+                "== Pair 3 ==",
+                "- Compare [9] vs [[8,7,6]]",
+                "  - Compare 9 vs [8,7,6]",
+                "    - Mixed types; convert left to [9] and retry comparison",
+                "    - Compare [9] vs [8,7,6]",
+                "      - Compare 9 vs 8",
+                "        - Right side is smaller, so inputs are not in the right order",
+            ]
+        );
+        assert!(steps.contains(
+            &"  - Left side ran out of items, so inputs are in the right order".to_string()
+        ));
+        assert_eq!(explain(include_str!("../input-example"), 3).len(), 3);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 5623);
+        assert_eq!(part2_answer, 20570);
+    }
+}
@@ -0,0 +1,217 @@
+#![no_std]
+
+extern crate alloc;
+
+use crate::Content::{Air, Rock, Sand};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+
+pub fn run(input: &str) -> (u32, u32) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> u32 {
+    let mut cm = CaveMap::parse_input(input, false);
+    for i in 0..100000 {
+        if !cm.drop_sand() {
+            trace::event!("day14.sand", grains = i, floor = false);
+            return i;
+        }
+    }
+    0
+}
+
+pub fn part2(input: &str) -> u32 {
+    let mut cm = CaveMap::parse_input(input, true);
+    for i in 0..100000 {
+        if !cm.drop_sand() {
+            trace::event!("day14.sand", grains = i, floor = true);
+            return i;
+        }
+    }
+    0
+}
+
+// Draws the cave once sand stops coming to rest, for part 1 or with the floor of part 2.
+pub fn cave(input: &str, floor: bool) -> String {
+    let mut cm = CaveMap::parse_input(input, floor);
+    while cm.drop_sand() {}
+    cm.render()
+}
+
+// Narrates part 1 grain by grain, for at most `steps` grains of sand.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let mut cm = CaveMap::parse_input(input, false);
+    let mut narration = Vec::new();
+    for i in 1..=steps {
+        match cm.pour_sand() {
+            Some((x, y)) => narration.push(format!("grain {} comes to rest at {},{}", i, x, y)),
+            None => {
+                narration.push(format!("grain {} flows into the abyss below", i));
+                break;
+            }
+        }
+    }
+    narration
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Content {
+    Air,
+    Sand,
+    Rock,
+}
+
+pub const N: usize = 700;
+#[derive(Debug)]
+pub struct CaveMap {
+    pub blocked: [[Content; N]; N],
+    pub min_x: usize,
+    pub min_y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
+impl CaveMap {
+    pub fn parse_input(input: &str, floor: bool) -> CaveMap {
+        let mut points: Vec<Vec<(usize, usize)>> = Vec::new();
+        for line in input.trim_end().split('\n') {
+            points.push(
+                line.split(" -> ")
+                    .map(|p| p.split(','))
+                    .map(|mut s| {
+                        (
+                            s.next().unwrap().parse::<usize>().unwrap(),
+                            s.next().unwrap().parse::<usize>().unwrap(),
+                        )
+                    })
+                    .collect(),
+            );
+        }
+
+        let mut cm: CaveMap = CaveMap {
+            blocked: [[Air; N]; N],
+            min_x: N,
+            min_y: N,
+            max_x: 0,
+            max_y: 0,
+        };
+
+        for path in points {
+            for i in 1..path.len() {
+                for x in cmp::min(path[i - 1].0, path[i].0)..=cmp::max(path[i - 1].0, path[i].0) {
+                    for y in cmp::min(path[i - 1].1, path[i].1)..=cmp::max(path[i - 1].1, path[i].1)
+                    {
+                        cm.block_point(Rock, x, y);
+                    }
+                }
+            }
+        }
+
+        if floor {
+            for x in 0..N {
+                cm.blocked[cm.max_y + 2][x] = Rock;
+            }
+        }
+
+        cm
+    }
+
+    fn block_point(&mut self, content: Content, x: usize, y: usize) {
+        self.blocked[y][x] = content;
+        self.min_x = cmp::min(x, self.min_x);
+        self.min_y = cmp::min(y, self.min_y);
+        self.max_x = cmp::max(x, self.max_x);
+        self.max_y = cmp::max(y, self.max_y);
+    }
+
+    // Draws the part of the cave that holds rock or sand as the puzzle does, with the source of
+    // the sand at 500,0 marked by a +.
+    pub fn render(&self) -> String {
+        let mut s = String::new();
+        for y in 0..=self.max_y {
+            for x in self.min_x..=self.max_x {
+                s.push(match self.blocked[y][x] {
+                    Air if (x, y) == (500, 0) => '+',
+                    Air => '.',
+                    Sand => 'o',
+                    Rock => '#',
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    pub fn drop_sand(&mut self) -> bool {
+        self.pour_sand().is_some()
+    }
+
+    // drops a grain of sand and returns where it comes to rest, if it does
+    fn pour_sand(&mut self) -> Option<(usize, usize)> {
+        let mut x = 500;
+        let mut y = 0;
+
+        if self.blocked[y][x] == Sand {
+            return None;
+        }
+
+        loop {
+            if self.blocked[y + 1][x] == Air {
+                y += 1;
+            } else if self.blocked[y + 1][x - 1] == Air {
+                y += 1;
+                x -= 1;
+            } else if self.blocked[y + 1][x + 1] == Air {
+                y += 1;
+                x += 1;
+            } else {
+                break;
+            }
+            if y == N - 1 {
+                return None;
+            }
+        }
+
+        self.block_point(Sand, x, y);
+        Some((x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 24);
+        assert_eq!(part2_answer, 93);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 100);
+        assert_eq!(steps[0], "grain 1 comes to rest at 500,8");
+        assert_eq!(steps[1], "grain 2 comes to rest at 499,8");
+        assert_eq!(steps[24], "grain 25 flows into the abyss below");
+        assert_eq!(steps.len(), 25);
+    }
+
+    #[test]
+    fn test_cave() {
+        let example = include_str!("../input-example");
+        snapshot::assert_snapshot!("example-part1", cave(example, false));
+        snapshot::assert_snapshot!("example-part2", cave(example, true));
+        snapshot::assert_snapshot!("input-part1", cave(include_str!("../input"), false));
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 901);
+        assert_eq!(part2_answer, 24589);
+    }
+}
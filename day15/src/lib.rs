@@ -0,0 +1,186 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+
+pub fn run(input: &str, y: i32, max: i32) -> (u32, i64) {
+    (part1(input, y), part2(input, max))
+}
+
+pub fn part1(input: &str, y: i32) -> u32 {
+    let mut part1_answer: u32 = 0;
+
+    let srs = parse_input(input);
+    'outer: for x in -5000000..5000000 {
+        for sr in &srs {
+            if sr.beacon.0 == x && sr.beacon.1 == y {
+                continue 'outer;
+            }
+        }
+
+        for sr in &srs {
+            let d = distance(sr.sensor.0, sr.sensor.1, x, y);
+            let z = d <= sr.distance;
+            if z {
+                part1_answer += 1;
+                continue 'outer;
+            }
+        }
+    }
+    part1_answer
+}
+
+pub fn part2(input: &str, max: i32) -> i64 {
+    let srs = parse_input(input);
+    for sr in &srs {
+        let a = (sr.sensor.0, sr.sensor.1 - sr.distance);
+        let b = (sr.sensor.0 + sr.distance, sr.sensor.1);
+        let c = (sr.sensor.0, sr.sensor.1 + sr.distance);
+        let d = (sr.sensor.0 - sr.distance, sr.sensor.1);
+        if let Some(ans) = is_free(&srs, max, a.0 + 1, a.1 + 1, b.0 + 1, b.1) {
+            return ans;
+        }
+        if let Some(ans) = is_free(&srs, max, b.0, b.1 + 1, c.0, c.1 + 1) {
+            return ans;
+        }
+        if let Some(ans) = is_free(&srs, max, c.0 - 1, c.1, d.0 - 1, d.1) {
+            return ans;
+        }
+        if let Some(ans) = is_free(&srs, max, a.0, a.1 - 1, d.0 - 1, d.1) {
+            return ans;
+        }
+    }
+    0
+}
+
+// Narrates part 1 sensor by sensor, for at most `steps` sensors: which stretch of row `y` each
+// one rules out.
+pub fn explain(input: &str, y: i32, steps: usize) -> Vec<String> {
+    let mut narration = Vec::new();
+    for sr in parse_input(input).iter().take(steps) {
+        let reach = sr.distance - i32::abs(y - sr.sensor.1);
+        let coverage = if reach < 0 {
+            format!("does not reach row {}", y)
+        } else {
+            format!(
+                "covers x={}..={} of row {}",
+                sr.sensor.0 - reach,
+                sr.sensor.0 + reach,
+                y
+            )
+        };
+        narration.push(format!(
+            "sensor at x={}, y={} with its beacon at x={}, y={} (distance {}) {}",
+            sr.sensor.0, sr.sensor.1, sr.beacon.0, sr.beacon.1, sr.distance, coverage
+        ));
+    }
+    narration
+}
+
+fn is_free(srs: &Vec<SensorReading>, max: i32, x1: i32, y1: i32, x2: i32, y2: i32) -> Option<i64> {
+    let min = 0;
+    let x1 = cmp::max(cmp::min(x1, max), min);
+    let x2 = cmp::max(cmp::min(x2, max), min);
+    let y1 = cmp::max(cmp::min(y1, max), min);
+    let y2 = cmp::max(cmp::min(y2, max), min);
+    let dx = if x1 <= x2 { 1 } else { -1 };
+    let dy = if y1 <= y2 { 1 } else { -1 };
+    let mut x = x1;
+    let mut y = y1;
+    'outer: while x != x2 && y != y2 {
+        for sr in srs {
+            let d = distance(sr.sensor.0, sr.sensor.1, x, y);
+            let z = d <= sr.distance;
+            if z {
+                x += dx;
+                y += dy;
+                continue 'outer;
+            }
+        }
+        return Some((4000000i64 * x as i64) + y as i64);
+    }
+    None
+}
+
+#[derive(Debug)]
+pub struct SensorReading {
+    pub sensor: (i32, i32),
+    pub beacon: (i32, i32),
+    pub distance: i32,
+}
+
+pub fn distance(x1: i32, y1: i32, x2: i32, y2: i32) -> i32 {
+    i32::abs(x2 - x1) + i32::abs(y2 - y1)
+}
+
+pub fn parse_input(input: &str) -> Vec<SensorReading> {
+    let mut ret = Vec::new();
+    for line in input.trim_end().split('\n') {
+        let (sensor, beacon) = line
+            .strip_prefix("Sensor at ")
+            .and_then(|s| s.split_once(": closest beacon is at "))
+            .unwrap();
+        let sensor = position(sensor);
+        let beacon = position(beacon);
+        let distance = distance(sensor.0, sensor.1, beacon.0, beacon.1);
+        let sr = SensorReading {
+            sensor,
+            beacon,
+            distance,
+        };
+        ret.push(sr);
+    }
+    ret
+}
+
+// Parses "x=2, y=-18".
+fn position(s: &str) -> (i32, i32) {
+    let (x, y) = s
+        .strip_prefix("x=")
+        .and_then(|s| s.split_once(", y="))
+        .unwrap();
+    (x.parse().unwrap(), y.parse().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let lines = parse_input(include_str!("../input-example"));
+        assert_eq!(lines.len(), 14);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"), 10, 20);
+        assert_eq!(part1_answer, 26);
+        assert_eq!(part2_answer, 56000011);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 10, 100);
+        assert_eq!(steps.len(), 14);
+        assert_eq!(
+            steps[0],
+            "sensor at x=2, y=18 with its beacon at x=-2, y=15 (distance 7) does not reach row 10"
+        );
+        assert_eq!(
+            steps[6],
+            "sensor at x=8, y=7 with its beacon at x=2, y=10 (distance 9) covers x=2..=14 of row 10"
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"), 2000000, 4000000);
+        assert_eq!(part1_answer, 6124805);
+        assert_eq!(part2_answer, 12555527364986);
+    }
+}
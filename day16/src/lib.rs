@@ -0,0 +1,356 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    let valves = parse_input(input);
+    let shortest_paths = shortest_paths(&valves);
+    max_released_pressure(&valves, &shortest_paths)
+}
+
+pub fn part2(input: &str) -> usize {
+    let valves = parse_input(input);
+    let shortest_paths = shortest_paths(&valves);
+    max_released_pressure2(&valves, &shortest_paths)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Valve {
+    pub name: String,
+    pub index: usize,
+    pub flow_rate: usize,
+    pub neighbours: Vec<usize>,
+    pub neighbour_names: Vec<String>,
+}
+
+pub fn parse_input(input: &str) -> Vec<Valve> {
+    let mut valves = Vec::new();
+    let mut valve_indices = BTreeMap::new();
+    for (index, line) in input.trim_end().split('\n').enumerate() {
+        let (name, rest) = line
+            .strip_prefix("Valve ")
+            .and_then(|s| s.split_once(" has flow rate="))
+            .unwrap();
+        let (flow_rate, tunnels) = rest.split_once("; ").unwrap();
+        // "tunnel leads to valve GG" or "tunnels lead to valves DD, II, BB"
+        let (_, neighbour_names) = tunnels.split_once(" valve").unwrap();
+        let neighbour_names = neighbour_names
+            .trim_start_matches('s')
+            .trim_start()
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect();
+        let name = name.to_string();
+        valve_indices.insert(name.clone(), index);
+        let flow_rate = flow_rate.parse().unwrap();
+        let valve = Valve {
+            name,
+            index,
+            flow_rate,
+            neighbours: Vec::new(),
+            neighbour_names,
+        };
+        valves.push(valve);
+    }
+
+    for valve in &mut valves {
+        for neighbour_name in &valve.neighbour_names {
+            let neighbour_index = valve_indices.get(neighbour_name).unwrap();
+            valve.neighbours.push(*neighbour_index);
+        }
+    }
+
+    valves
+}
+
+pub fn shortest_paths(valves: &Vec<Valve>) -> Vec<Vec<usize>> {
+    // all-pairs shortest paths for valves, using the Floyd-Warshall algorithm
+    // https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+    let mut distances: Vec<Vec<usize>> = Vec::new();
+
+    for _ in 0..valves.len() {
+        let row = vec![usize::MAX; valves.len()];
+        distances.push(row);
+    }
+
+    for (i, d) in distances.iter_mut().enumerate() {
+        d[i] = 0;
+    }
+
+    for valve in valves {
+        for neighbour in &valve.neighbours {
+            distances[valve.index][*neighbour] = 1;
+        }
+    }
+
+    for k in 0..valves.len() {
+        for i in 0..valves.len() {
+            for j in 0..valves.len() {
+                if i == j {
+                    continue;
+                }
+                distances[i][j] = cmp::min(
+                    distances[i][j],
+                    distances[i][k].saturating_add(distances[k][j]),
+                );
+            }
+        }
+    }
+
+    distances
+}
+
+// Narrates part 1 along the best order to open the valves in, for at most `steps` valves.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let valves = parse_input(input);
+    let distances = shortest_paths(&valves);
+    let start = valves.iter().position(|v| v.name == "AA").unwrap();
+    let (_, route) = best_route(&valves, &distances, start, 0, 30);
+    let mut narration = Vec::new();
+    let (mut pos, mut elapsed, mut relieved) = (start, 0, 0);
+    for &next in route.iter().take(steps) {
+        let valve = &valves[next];
+        let distance = distances[pos][next];
+        elapsed += distance + 1;
+        let released = (30 - elapsed) * valve.flow_rate;
+        relieved += released;
+        narration.push(format!(
+            "minute {}: move {} minute(s) to {} and open it, releasing {} for the remaining {} \
+             minutes = {}, {} in total",
+            elapsed,
+            distance,
+            valve.name,
+            valve.flow_rate,
+            30 - elapsed,
+            released,
+            relieved
+        ));
+        pos = next;
+    }
+    narration
+}
+
+// The most pressure that can be released from `pos` at minute `elapsed` and the valves to open
+// for it, in order. Unlike simulate this keeps the order, which explain needs.
+fn best_route(
+    valves: &[Valve],
+    distances: &[Vec<usize>],
+    pos: usize,
+    elapsed: usize,
+    time_limit: usize,
+) -> (usize, Vec<usize>) {
+    let mut best = (0, Vec::new());
+    let mut opened = vec![false; valves.len()];
+    let mut route = Vec::new();
+    search(
+        valves,
+        distances,
+        (pos, elapsed, 0),
+        time_limit,
+        &mut opened,
+        &mut route,
+        &mut best,
+    );
+    best
+}
+
+fn search(
+    valves: &[Valve],
+    distances: &[Vec<usize>],
+    (pos, elapsed, relieved): (usize, usize, usize),
+    time_limit: usize,
+    opened: &mut [bool],
+    route: &mut Vec<usize>,
+    best: &mut (usize, Vec<usize>),
+) {
+    if relieved > best.0 {
+        *best = (relieved, route.clone());
+    }
+    for next in 0..valves.len() {
+        let distance = distances[pos][next];
+        if opened[next] || valves[next].flow_rate == 0 || distance == usize::MAX {
+            continue;
+        }
+        let elapsed = elapsed + distance + 1;
+        if elapsed > time_limit {
+            continue;
+        }
+        let relieved = relieved + (time_limit - elapsed) * valves[next].flow_rate;
+        opened[next] = true;
+        route.push(next);
+        search(
+            valves,
+            distances,
+            (next, elapsed, relieved),
+            time_limit,
+            opened,
+            route,
+            best,
+        );
+        route.pop();
+        opened[next] = false;
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct State {
+    opened: BTreeSet<usize>,
+    pos: usize,
+    elapsed: usize,
+    relieved: usize,
+}
+
+pub fn max_released_pressure(valves: &[Valve], distances: &[Vec<usize>]) -> usize {
+    simulate(valves, distances, 30)
+        .values()
+        .copied()
+        .max()
+        .unwrap()
+}
+
+pub fn max_released_pressure2(valves: &[Valve], distances: &[Vec<usize>]) -> usize {
+    let max_relieved_valves = simulate(valves, distances, 26);
+    let mut max = 0;
+    let max_relieved_valves_vec: Vec<_> = max_relieved_valves.iter().collect();
+    for i in 0..max_relieved_valves_vec.len() {
+        for j in i + 1..max_relieved_valves_vec.len() {
+            let (v1, m1) = max_relieved_valves_vec[i];
+            let (v2, m2) = max_relieved_valves_vec[j];
+            if v1.is_disjoint(v2) {
+                max = cmp::max(max, m1 + m2);
+            }
+        }
+    }
+    max
+}
+
+fn simulate(
+    valves: &[Valve],
+    distances: &[Vec<usize>],
+    time_limit: usize,
+) -> BTreeMap<BTreeSet<usize>, usize> {
+    let usable_valves: Vec<usize> = valves
+        .iter()
+        .enumerate()
+        .filter(|(_i, v)| v.flow_rate > 0)
+        .map(|(i, _v)| i)
+        .collect();
+
+    let start = valves.iter().position(|v| v.name == "AA").unwrap();
+    let state = State {
+        opened: BTreeSet::new(),
+        pos: start,
+        elapsed: 0,
+        relieved: 0,
+    };
+    let mut queue: Vec<State> = Vec::new();
+    queue.push(state);
+
+    let mut max_relieved_valves: BTreeMap<BTreeSet<usize>, usize> = BTreeMap::new();
+
+    // the counters for the trace event are only kept while tracing
+    let tracing = trace::enabled();
+    let mut explored = 0;
+    let mut pruned = 0;
+    let mut max_queue = queue.len();
+    while let Some(state) = queue.pop() {
+        if tracing {
+            explored += 1;
+        }
+        let possible = usable_valves
+            .iter()
+            .filter(|v| **v != state.pos && !state.opened.contains(*v))
+            .collect::<Vec<_>>();
+        for &next in possible {
+            let distance = distances[state.pos][next];
+            if distance == usize::MAX {
+                if tracing {
+                    pruned += 1;
+                }
+                continue;
+            }
+            let mut opened = state.opened.clone();
+            opened.insert(next);
+            let elapsed = state.elapsed + distance + 1;
+            if elapsed > time_limit {
+                if tracing {
+                    pruned += 1;
+                }
+                continue;
+            }
+            let relieved = state.relieved + (time_limit - elapsed) * valves[next].flow_rate;
+            let state = State {
+                opened,
+                pos: next,
+                elapsed,
+                relieved,
+            };
+            max_relieved_valves
+                .entry(state.opened.clone())
+                .and_modify(|val| *val = relieved.max(*val))
+                .or_insert(relieved);
+            queue.push(state);
+        }
+        if tracing {
+            max_queue = max_queue.max(queue.len());
+        }
+    }
+
+    trace::event!(
+        "day16.simulate",
+        time_limit = time_limit,
+        explored = explored,
+        pruned = pruned,
+        max_queue = max_queue,
+        valve_sets = max_relieved_valves.len(),
+    );
+    max_relieved_valves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let valves = parse_input(include_str!("../input-example"));
+        assert_eq!(valves.len(), 10);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 1651);
+        assert_eq!(part2_answer, 1707);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 100);
+        assert_eq!(steps.len(), 6);
+        assert_eq!(
+            steps[0],
+            "minute 2: move 1 minute(s) to DD and open it, releasing 20 for the remaining 28 \
+             minutes = 560, 560 in total"
+        );
+        assert!(steps[5].ends_with(", 1651 in total"), "{}", steps[5]);
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 1792);
+        assert_eq!(part2_answer, 2587);
+    }
+}
@@ -0,0 +1,351 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    simulate(&parse_input(input), &rocks(), 2022)
+}
+
+pub fn part2(input: &str) -> usize {
+    simulate(&parse_input(input), &rocks(), 1000000000000)
+}
+
+// Narrates part 1 rock by rock, for at most `steps` rocks.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    const SHAPES: [&str; 5] = ["-", "+", "L", "|", "square"];
+    let jets = parse_input(input);
+    let rocks = rocks();
+    let mut chamber: Chamber = Vec::new();
+    let mut jet_idx = 0;
+    let mut narration = Vec::new();
+    for i in 0..steps.min(2022) {
+        let first_jet = jet_idx;
+        let (x, y) = drop_rock(&mut chamber, &rocks[i % rocks.len()], &jets, &mut jet_idx);
+        narration.push(format!(
+            "rock {} ({}) is pushed by {} jets and comes to rest with its bottom left corner at \
+             x={}, y={}, the tower height is {}",
+            i + 1,
+            SHAPES[i % SHAPES.len()],
+            jet_idx - first_jet,
+            x,
+            y,
+            chamber.len()
+        ));
+    }
+    narration
+}
+
+// Draws the tower once `num_rocks` rocks have come to rest.
+pub fn tower(input: &str, num_rocks: usize) -> String {
+    let jets = parse_input(input);
+    let rocks = rocks();
+    let mut chamber: Chamber = Vec::new();
+    let mut jet_idx = 0;
+    for i in 0..num_rocks {
+        drop_rock(&mut chamber, &rocks[i % rocks.len()], &jets, &mut jet_idx);
+    }
+    render_chamber(&chamber, None)
+}
+
+fn rocks() -> Vec<Vec<Vec<bool>>> {
+    // rocks are upside down
+    vec![
+        vec![vec![true, true, true, true]],
+        vec![
+            vec![false, true, false],
+            vec![true, true, true],
+            vec![false, true, false],
+        ],
+        vec![
+            vec![true, true, true],
+            vec![false, false, true],
+            vec![false, false, true],
+        ],
+        vec![vec![true], vec![true], vec![true], vec![true]],
+        vec![vec![true, true], vec![true, true]],
+    ]
+}
+
+fn simulate(jets: &[Jet], rocks: &[Vec<Vec<bool>>], num_rocks: usize) -> usize {
+    let mut chamber: Chamber = Vec::new();
+    let mut rock_idx: usize = 0;
+    let mut jet_idx: usize = 0;
+
+    let mut num_rocks = num_rocks;
+    let mut pattern: Option<Pattern> = None;
+    let mut shortcut = 0;
+
+    while rock_idx < num_rocks {
+        // SPAWN ROCK
+        let rock = &rocks[rock_idx % rocks.len()];
+        rock_idx += 1;
+
+        if shortcut == 0 {
+            if let Some(new_pattern) = check_pattern(&chamber, rock_idx) {
+                if pattern.is_none() {
+                    trace::event!(
+                        "day17.pattern",
+                        distance = new_pattern.distance,
+                        cycles = new_pattern.cycles,
+                        rocks = new_pattern.rocks,
+                    );
+                    pattern = Some(new_pattern);
+                } else if let Some(old_pattern) = &pattern {
+                    if new_pattern.cycles > old_pattern.cycles {
+                        trace::event!(
+                            "day17.pattern",
+                            distance = new_pattern.distance,
+                            cycles = new_pattern.cycles,
+                            rocks = new_pattern.rocks,
+                        );
+                        if new_pattern.cycles > 3 {
+                            let diff = new_pattern.rocks - old_pattern.rocks;
+                            let remaining_rocks = num_rocks - rock_idx;
+                            num_rocks = rock_idx + (remaining_rocks % diff);
+                            shortcut = (remaining_rocks / diff) * new_pattern.distance;
+                            trace::event!(
+                                "day17.shortcut",
+                                skipped_rocks = remaining_rocks - remaining_rocks % diff,
+                                skipped_height = shortcut,
+                            );
+                        }
+                        pattern = Some(new_pattern);
+                    }
+                }
+            }
+        }
+
+        drop_rock(&mut chamber, rock, jets, &mut jet_idx);
+    }
+    shortcut + chamber.len()
+}
+
+// Lets a rock fall until it comes to rest, pushed around by the jets from `jet_idx` on, and
+// returns where it ended up.
+fn drop_rock(
+    chamber: &mut Chamber,
+    rock: &[Vec<bool>],
+    jets: &[Jet],
+    jet_idx: &mut usize,
+) -> (isize, isize) {
+    let mut movement = Movement::Jet;
+    // Each rock appears so that its left edge is two units away from the left wall
+    let mut x: isize = 2;
+    // and its bottom edge is three units above the highest rock in the room (or the floor, if there isn't one).
+    let mut y: isize = (chamber.len() + 3) as isize;
+    loop {
+        match &movement {
+            Movement::Jet => {
+                let jet = &jets[*jet_idx % jets.len()];
+                match jet {
+                    Jet::Left => {
+                        if is_clear(chamber, rock, x - 1, y) {
+                            x -= 1;
+                        }
+                    }
+                    Jet::Right => {
+                        if is_clear(chamber, rock, x + 1, y) {
+                            x += 1;
+                        }
+                    }
+                }
+                *jet_idx += 1;
+                movement = Movement::Down;
+            }
+            Movement::Down => {
+                if is_clear(chamber, rock, x, y - 1) {
+                    y -= 1;
+                } else {
+                    add_rock(chamber, rock, x, y);
+                    return (x, y);
+                }
+                movement = Movement::Jet;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pattern {
+    distance: usize,
+    cycles: usize,
+    rocks: usize,
+}
+
+fn check_pattern(chamber: &Chamber, rocks: usize) -> Option<Pattern> {
+    let h = chamber.len() / 3;
+    for offset in 0..h {
+        'distance: for distance in 10..h {
+            let mut cycles = 0;
+            loop {
+                cycles += 1;
+                if (offset + distance * (cycles + 1)) >= chamber.len() {
+                    break;
+                }
+                for i in 0..distance {
+                    if chamber[offset + i] != chamber[offset + i + distance * cycles] {
+                        continue 'distance;
+                    }
+                }
+            }
+            return Some(Pattern {
+                distance,
+                cycles,
+                rocks,
+            });
+        }
+    }
+    None
+}
+
+fn is_clear(chamber: &Chamber, rock: &[Vec<bool>], x: isize, y: isize) -> bool {
+    // check horizontal clearance
+    if x < 0 || x + rock[0].len() as isize > 7 {
+        return false;
+    }
+
+    // check vertical clearance
+    let dy = y - chamber.len() as isize;
+    let h = rock.len() as isize;
+    if dy >= 0 {
+        true
+    } else if -dy > chamber.len() as isize {
+        false
+    } else {
+        let mdy = cmp::min(dy + h, 0);
+        for i in dy..mdy {
+            let ry = (i - dy) as usize;
+            let rock_line = &rock[ry];
+            for (j, v) in rock_line.iter().enumerate() {
+                let a = (chamber.len() as isize + i) as usize;
+                let b = x as usize + j;
+                if *v && chamber[a][b] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn add_rock(chamber: &mut Chamber, rock: &[Vec<bool>], x: isize, y: isize) {
+    let dy = rock.len() as isize + y - chamber.len() as isize;
+    for _ in 0..dy {
+        chamber.push([false; 7]);
+    }
+    for ry in 0..rock.len() {
+        for rx in 0..rock[ry].len() {
+            chamber[y as usize + ry][x as usize + rx] |= rock[ry][rx];
+        }
+    }
+}
+
+// Draws the chamber as the puzzle does, with the falling rock, if any, at x, y.
+fn render_chamber(chamber: &Chamber, falling: Option<(&[Vec<bool>], isize, isize)>) -> String {
+    let (rock, x, y): (&[Vec<bool>], isize, isize) = falling.unwrap_or((&[], 0, 0));
+    let top = cmp::max(chamber.len() as isize, y + rock.len() as isize);
+    let mut s = String::new();
+    for ry in (0..top).rev() {
+        s.push('|');
+        for rx in 0..7 {
+            let w = rock.first().map_or(0, |row| row.len()) as isize;
+            let h = rock.len() as isize;
+            if (x..x + w).contains(&rx)
+                && (y..y + h).contains(&ry)
+                && rock[(ry - y) as usize][(rx - x) as usize]
+            {
+                s.push('@');
+            } else if ry < chamber.len() as isize && chamber[ry as usize][rx as usize] {
+                s.push('#');
+            } else {
+                s.push('.');
+            }
+        }
+        s.push_str("|\n");
+    }
+    s.push_str("+-------+\n");
+    s
+}
+
+pub type Chamber = Vec<[bool; 7]>;
+
+#[derive(Debug)]
+enum Movement {
+    Jet,
+    Down,
+}
+
+#[derive(Debug)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+pub fn parse_input(input: &str) -> Vec<Jet> {
+    let mut jets = Vec::new();
+    for c in input.trim_end().chars() {
+        let j = match c {
+            '<' => Jet::Left,
+            '>' => Jet::Right,
+            _ => panic!("unknown jet: {}", c),
+        };
+        jets.push(j);
+    }
+    jets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_parse() {
+        let jets = parse_input(include_str!("../input-example"));
+        assert_eq!(jets.len(), 40);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example"));
+        assert_eq!(part1_answer, 3068);
+        assert_eq!(part2_answer, 1514285714288);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example"), 2);
+        assert_eq!(
+            steps,
+            [
+                "rock 1 (-) is pushed by 4 jets and comes to rest with its bottom left corner at \
+                 x=2, y=0, the tower height is 1",
+                "rock 2 (+) is pushed by 4 jets and comes to rest with its bottom left corner at \
+                 x=2, y=1, the tower height is 4",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tower() {
+        let example = include_str!("../input-example");
+        snapshot::assert_snapshot!("example-10-rocks", tower(example, 10));
+        snapshot::assert_snapshot!("input-100-rocks", tower(include_str!("../input"), 100));
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 3193);
+        assert_eq!(part2_answer, 1577650429835);
+    }
+}
@@ -0,0 +1,163 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub const N: usize = 25;
+const ADJACENCY: [(isize, isize, isize); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+pub type Scan = [[[bool; N]; N]; N];
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    calculate_surface_area(parse_input(input), None)
+}
+
+pub fn part2(input: &str) -> usize {
+    let scan = parse_input(input);
+
+    // flood fill to find all voxels that are connected to the outside
+    let mut outside = Scan::default();
+    let mut queue: Vec<(usize, usize, usize)> = vec![(0, 0, 0)];
+    outside[0][0][0] = true;
+    while let Some((x, y, z)) = queue.pop() {
+        for (dx, dy, dz) in ADJACENCY.iter() {
+            let nx = x.saturating_add_signed(*dx);
+            let ny = y.saturating_add_signed(*dy);
+            let nz = z.saturating_add_signed(*dz);
+            if nx >= N || ny >= N || nz >= N || outside[nz][ny][nx] || scan[nz][ny][nx] {
+                continue;
+            }
+            outside[nz][ny][nx] = true;
+            queue.push((nx, ny, nz));
+        }
+    }
+
+    calculate_surface_area(scan, Some(outside))
+}
+
+// Narrates part 1 cube by cube, for at most `steps` cubes: how many of its sides are not
+// covered by another cube.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let scan = parse_input(input);
+    let mut total = 0;
+    let mut narration = Vec::new();
+    for line in input.trim_end().split('\n').take(steps) {
+        let mut s = line.split(',').map(|s| s.parse::<usize>().unwrap() + 1);
+        let (x, y, z) = (s.next().unwrap(), s.next().unwrap(), s.next().unwrap());
+        let exposed = ADJACENCY
+            .iter()
+            .filter(|(dx, dy, dz)| {
+                let nx = x.saturating_add_signed(*dx);
+                let ny = y.saturating_add_signed(*dy);
+                let nz = z.saturating_add_signed(*dz);
+                nx >= N || ny >= N || nz >= N || !scan[nz][ny][nx]
+            })
+            .count();
+        total += exposed;
+        narration.push(format!(
+            "cube {}: {} sides exposed, {} in total",
+            line, exposed, total
+        ));
+    }
+    narration
+}
+
+pub fn calculate_surface_area(scan: Scan, outside: Option<[[[bool; 25]; 25]; 25]>) -> usize {
+    let mut surface_area = 0;
+    for z in 0..N {
+        for y in 0..N {
+            for x in 0..N {
+                if scan[z][y][x] {
+                    for (dx, dy, dz) in ADJACENCY.iter() {
+                        let nx = x.saturating_add_signed(*dx);
+                        let ny = y.saturating_add_signed(*dy);
+                        let nz = z.saturating_add_signed(*dz);
+                        if nx >= N || ny >= N || nz >= N || !scan[nz][ny][nx] {
+                            if let Some(outside) = outside {
+                                if outside[nz][ny][nx] {
+                                    surface_area += 1;
+                                }
+                            } else {
+                                surface_area += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    surface_area
+}
+
+pub fn parse_input(input: &str) -> Scan {
+    let mut scan = Scan::default();
+    for line in input.trim_end().split('\n') {
+        let mut s = line.split(',').map(|s| s.parse::<usize>().unwrap() + 1);
+        let x = s.next().unwrap();
+        let y = s.next().unwrap();
+        let z = s.next().unwrap();
+        scan[z][y][x] = true;
+    }
+    scan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example1_parse() {
+        parse_input(include_str!("../input-example1"));
+    }
+
+    #[test]
+    fn test_example2_parse() {
+        parse_input(include_str!("../input-example1"));
+    }
+
+    #[test]
+    fn test_example1_answer() {
+        let (part1_answer, _) = run(include_str!("../input-example1"));
+        assert_eq!(part1_answer, 10);
+    }
+
+    #[test]
+    fn test_example2_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example2"));
+        assert_eq!(part1_answer, 64);
+        assert_eq!(part2_answer, 58);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain("1,1,1\n2,1,1\n", 2);
+        assert_eq!(
+            steps,
+            [
+                "cube 1,1,1: 5 sides exposed, 5 in total",
+                "cube 2,1,1: 5 sides exposed, 10 in total",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input"));
+        assert_eq!(part1_answer, 4512);
+        assert_eq!(part2_answer, 2554);
+    }
+}
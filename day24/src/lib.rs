@@ -0,0 +1,281 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub fn run(input: &str) -> (usize, usize) {
+    (part1(input), part2(input))
+}
+
+pub fn part1(input: &str) -> usize {
+    let valley = Valley::parse_input(input);
+    valley.find_path(valley.start, valley.end, 0)
+}
+
+pub fn part2(input: &str) -> usize {
+    let valley = Valley::parse_input(input);
+    let there = valley.find_path(valley.start, valley.end, 0);
+    let back = valley.find_path(valley.end, valley.start, there);
+    valley.find_path(valley.start, valley.end, back)
+}
+
+// Narrates part 1 minute by minute along a quickest way through the valley, for at most `steps`
+// minutes.
+pub fn explain(input: &str, steps: usize) -> Vec<String> {
+    let valley = Valley::parse_input(input);
+    let route = valley.route(valley.start, valley.end, 0);
+    route
+        .windows(2)
+        .take(steps)
+        .enumerate()
+        .map(|(i, step)| {
+            let ((x0, y0), (x, y)) = (step[0], step[1]);
+            let action = match (x as isize - x0 as isize, y as isize - y0 as isize) {
+                (0, 0) => "wait at",
+                (1, 0) => "move right to",
+                (-1, 0) => "move left to",
+                (0, 1) => "move down to",
+                _ => "move up to",
+            };
+            let goal = if (x, y) == valley.end {
+                ", reaching the goal"
+            } else {
+                ""
+            };
+            format!("minute {}: {} ({}, {}){}", i + 1, action, x, y, goal)
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+    Wall,
+    Ground,
+    Blizzard(Direction),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+// where the expedition came from to be at a position at a given minute
+type Parents = BTreeMap<((usize, usize), usize), (usize, usize)>;
+
+const ADJACENCY: [(isize, isize); 5] = [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)];
+
+#[derive(Debug)]
+pub struct Valley {
+    pub tiles: Vec<Vec<Tile>>,
+    // inner dimensions, excluding the surrounding walls
+    pub width: usize,
+    pub height: usize,
+    // blizzards wrap around, so the valley repeats itself every lcm(width, height) minutes
+    pub period: usize,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+impl Valley {
+    pub fn parse_input(input: &str) -> Valley {
+        let mut tiles: Vec<Vec<Tile>> = Vec::new();
+        for line in input.trim_end().split('\n') {
+            let row = line
+                .chars()
+                .map(|c| match c {
+                    '#' => Tile::Wall,
+                    '.' => Tile::Ground,
+                    '^' => Tile::Blizzard(Direction::Up),
+                    'v' => Tile::Blizzard(Direction::Down),
+                    '<' => Tile::Blizzard(Direction::Left),
+                    '>' => Tile::Blizzard(Direction::Right),
+                    _ => panic!("unknown tile: {}", c),
+                })
+                .collect();
+            tiles.push(row);
+        }
+
+        let width = tiles[0].len() - 2;
+        let height = tiles.len() - 2;
+        let start_x = tiles[0].iter().position(|t| *t == Tile::Ground).unwrap();
+        let end_x = tiles[height + 1]
+            .iter()
+            .position(|t| *t == Tile::Ground)
+            .unwrap();
+
+        Valley {
+            tiles,
+            width,
+            height,
+            period: lcm(width, height),
+            start: (start_x, 0),
+            end: (end_x, height + 1),
+        }
+    }
+
+    // Rather than moving every blizzard each minute, look back along the row and column to
+    // see whether a blizzard that started there would have reached (x, y) by minute t.
+    pub fn is_blocked(&self, x: usize, y: usize, t: usize) -> bool {
+        if self.tiles[y][x] == Tile::Wall {
+            return true;
+        }
+        if y == 0 || y == self.height + 1 {
+            // the entrance and exit are never hit by blizzards
+            return false;
+        }
+
+        let (w, h) = (self.width, self.height);
+        let (ix, iy) = (x - 1, y - 1);
+        let tw = t % w;
+        let th = t % h;
+        self.tiles[y][1 + (ix + w - tw) % w] == Tile::Blizzard(Direction::Right)
+            || self.tiles[y][1 + (ix + tw) % w] == Tile::Blizzard(Direction::Left)
+            || self.tiles[1 + (iy + h - th) % h][x] == Tile::Blizzard(Direction::Down)
+            || self.tiles[1 + (iy + th) % h][x] == Tile::Blizzard(Direction::Up)
+    }
+
+    pub fn find_path(&self, from: (usize, usize), to: (usize, usize), start_time: usize) -> usize {
+        self.search(from, to, start_time, None)
+    }
+
+    // The positions of the expedition on a quickest way from `from` to `to`, one per minute.
+    fn route(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        start_time: usize,
+    ) -> Vec<(usize, usize)> {
+        let mut parents = BTreeMap::new();
+        let mut t = self.search(from, to, start_time, Some(&mut parents));
+        let mut route = vec![to];
+        while t > start_time {
+            route.push(parents[&(*route.last().unwrap(), t)]);
+            t -= 1;
+        }
+        route.reverse();
+        route
+    }
+
+    // Breadth-first search over (position, time mod period) states. With `parents` it records
+    // where each (position, time) was reached from.
+    fn search(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        start_time: usize,
+        mut parents: Option<&mut Parents>,
+    ) -> usize {
+        let mut visited: BTreeSet<(usize, usize, usize)> = BTreeSet::new();
+        let mut queue: VecDeque<((usize, usize), usize)> = VecDeque::new();
+        visited.insert((from.0, from.1, start_time % self.period));
+        queue.push_back((from, start_time));
+
+        while let Some(((x, y), t)) = queue.pop_front() {
+            if (x, y) == to {
+                return t;
+            }
+            let t = t + 1;
+            for (dx, dy) in ADJACENCY.iter() {
+                let nx = match x.checked_add_signed(*dx) {
+                    Some(nx) if nx < self.width + 2 => nx,
+                    _ => continue,
+                };
+                let ny = match y.checked_add_signed(*dy) {
+                    Some(ny) if ny < self.height + 2 => ny,
+                    _ => continue,
+                };
+                if self.is_blocked(nx, ny, t) || !visited.insert((nx, ny, t % self.period)) {
+                    continue;
+                }
+                if let Some(parents) = parents.as_mut() {
+                    parents.insert(((nx, ny), t), (x, y));
+                }
+                queue.push_back(((nx, ny), t));
+            }
+        }
+
+        panic!("no path from {:?} to {:?}", from, to)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(5, 5), 5);
+        assert_eq!(lcm(6, 4), 12);
+        assert_eq!(lcm(120, 25), 600);
+    }
+
+    #[test]
+    fn test_example1_parse() {
+        let valley = Valley::parse_input(include_str!("../input-example1"));
+        assert_eq!(valley.width, 5);
+        assert_eq!(valley.height, 5);
+        assert_eq!(valley.period, 5);
+        assert_eq!(valley.start, (1, 0));
+        assert_eq!(valley.end, (5, 6));
+    }
+
+    #[test]
+    fn test_example1_blizzards() {
+        let valley = Valley::parse_input(include_str!("../input-example1"));
+        // the '>' blizzard moves one step right every minute and wraps around
+        assert!(valley.is_blocked(1, 2, 0));
+        assert!(valley.is_blocked(2, 2, 1));
+        assert!(valley.is_blocked(1, 2, 5));
+        assert!(!valley.is_blocked(1, 2, 1));
+        // the 'v' blizzard moves down and wraps around to the top
+        assert!(valley.is_blocked(4, 4, 0));
+        assert!(valley.is_blocked(4, 5, 1));
+        assert!(valley.is_blocked(4, 1, 2));
+    }
+
+    #[test]
+    fn test_example2_parse() {
+        let valley = Valley::parse_input(include_str!("../input-example2"));
+        assert_eq!(valley.width, 6);
+        assert_eq!(valley.height, 4);
+        assert_eq!(valley.period, 12);
+    }
+
+    #[test]
+    fn test_example2_answer() {
+        let (part1_answer, part2_answer) = run(include_str!("../input-example2"));
+        assert_eq!(part1_answer, 18);
+        assert_eq!(part2_answer, 54);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain(include_str!("../input-example2"), 100);
+        assert_eq!(steps.len(), 18);
+        assert_eq!(steps[0], "minute 1: wait at (1, 0)");
+        assert_eq!(
+            steps[17],
+            "minute 18: move down to (6, 5), reaching the goal"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::fs;
+use std::process;
+
+fn main() {
+    // there is no puzzle input checked in for this day, so it is read when run rather than
+    // included
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/input").to_string());
+    let input = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("input missing: {}: {}", path, e);
+        eprintln!("usage: day24 [<input>]");
+        process::exit(1);
+    });
+    let (part1_answer, part2_answer) = day24::run(&input);
+    println!("part 1 answer: {}", part1_answer);
+    println!("part 2 answer: {}", part2_answer);
+}
@@ -0,0 +1,137 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Generates aoc.h in OUT_DIR from the constants, #[repr(C)] structs and extern "C" functions in
+// src/lib.rs, carrying over the comments in front of them. The copy checked in as include/aoc.h is
+// compared with it by the tests, which update it when run with AOC_UPDATE_SNAPSHOTS=1.
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source = fs::read_to_string(Path::new(&dir).join("src/lib.rs")).unwrap();
+    let out = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out).join("aoc.h"), header(&source)).unwrap();
+}
+
+fn header(source: &str) -> String {
+    let mut out = String::from(
+        "// Generated from src/lib.rs by build.rs, do not edit.\n\n\
+         #ifndef AOC_H\n#define AOC_H\n\n\
+         #include <stddef.h>\n#include <stdint.h>\n\n\
+         #ifdef __cplusplus\nextern \"C\" {\n#endif\n",
+    );
+    let mut comments: Vec<&str> = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line == "#[cfg(test)]" {
+            break;
+        }
+        if let Some(comment) = line.strip_prefix("///").or(line.strip_prefix("//")) {
+            comments.push(comment);
+            continue;
+        }
+        let item = if let Some(constant) = line.strip_prefix("pub const ") {
+            // NAME: i32 = 0;
+            let (name, rest) = constant.split_once(':').unwrap();
+            let value = rest.split_once('=').unwrap().1.trim().trim_end_matches(';');
+            Some(format!("#define {} {}\n", name, value))
+        } else if line == "#[repr(C)]" {
+            let name = lines
+                .next()
+                .and_then(|l| l.strip_prefix("pub struct "))
+                .and_then(|l| l.strip_suffix(" {"))
+                .expect("#[repr(C)] must be followed by a pub struct");
+            let mut fields = String::new();
+            for field in lines.by_ref().take_while(|l| *l != "}") {
+                let (field, ty) = field
+                    .strip_prefix("pub ")
+                    .and_then(|f| f.trim_end_matches(',').split_once(": "))
+                    .expect("struct fields must be pub");
+                fields.push_str(&format!("    {};\n", declaration(ty, field)));
+            }
+            Some(format!(
+                "typedef struct {} {{\n{}}} {};\n",
+                name, fields, name
+            ))
+        } else if line.contains("extern \"C\" fn ") {
+            let mut signature = line.to_string();
+            while !signature.ends_with('{') {
+                signature.push_str(lines.next().unwrap());
+            }
+            Some(format!("{};\n", function(&signature)))
+        } else {
+            None
+        };
+        if let Some(item) = item {
+            out.push('\n');
+            for comment in &comments {
+                out.push_str(&format!("//{}\n", comment));
+            }
+            out.push_str(&item);
+        }
+        if !line.starts_with("#[") {
+            comments.clear();
+        }
+    }
+    out.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+    out
+}
+
+// `pub unsafe extern "C" fn name(a: u8, b: *const u8) -> Ret {`
+fn function(signature: &str) -> String {
+    let signature = &signature[signature.find(" fn ").unwrap() + 4..];
+    let (name, rest) = signature.split_once('(').unwrap();
+    let (args, rest) = rest.rsplit_once(')').unwrap();
+    let ret = rest
+        .trim()
+        .trim_end_matches('{')
+        .trim()
+        .strip_prefix("-> ")
+        .map_or("void".to_string(), c_type);
+    let args: Vec<String> = args
+        .split(',')
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| {
+            let (name, ty) = arg.split_once(": ").unwrap();
+            declaration(ty, name)
+        })
+        .collect();
+    let args = if args.is_empty() {
+        "void".to_string()
+    } else {
+        args.join(", ")
+    };
+    format!("{} {}({})", ret, name, args)
+}
+
+fn declaration(ty: &str, name: &str) -> String {
+    let ty = c_type(ty);
+    if ty.ends_with('*') {
+        format!("{}{}", ty, name)
+    } else {
+        format!("{} {}", ty, name)
+    }
+}
+
+fn c_type(ty: &str) -> String {
+    if let Some(ty) = ty.strip_prefix("*const ") {
+        return format!("const {} *", c_type(ty));
+    }
+    if let Some(ty) = ty.strip_prefix("*mut ") {
+        return format!("{} *", c_type(ty));
+    }
+    match ty {
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "usize" => "size_t",
+        "c_char" => "char",
+        "bool" => "bool",
+        name => name,
+    }
+    .to_string()
+}
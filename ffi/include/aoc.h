@@ -0,0 +1,61 @@
+// Generated from src/lib.rs by build.rs, do not edit.
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// the answer is in `answer`
+#define AOC_OK 0
+
+// the day is not solved
+#define AOC_UNKNOWN_DAY 1
+
+// the day has no such part
+#define AOC_UNKNOWN_PART 2
+
+// the input is not valid UTF-8, or a null pointer with a non-zero length
+#define AOC_INVALID_INPUT 3
+
+// the solver panicked, typically on malformed input
+#define AOC_PANIC 4
+
+// The result of aoc_solve and aoc_solve_year. `answer` is a NUL terminated string owned by the
+// caller, to be released with aoc_free. It holds the answer if `status` is AOC_OK and an error
+// message otherwise.
+typedef struct AocAnswer {
+    int32_t status;
+    char *answer;
+} AocAnswer;
+
+// Solves one part (1 or 2) of a day of 2022 for the `len` bytes of input at `input`.
+//
+// # Safety
+//
+// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+AocAnswer aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t len);
+
+// Like aoc_solve, for a day of the given year.
+//
+// # Safety
+//
+// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+AocAnswer aoc_solve_year(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input, size_t len);
+
+// Releases an answer returned by aoc_solve. Null is ignored.
+//
+// # Safety
+//
+// `answer` must come from aoc_solve or aoc_solve_year and not have been released before.
+void aoc_free(char *answer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,142 @@
+// A C ABI for the solvers, so they can be driven from C and other languages. The C header,
+// include/aoc.h, is generated from this file by build.rs and checked by the tests; keep the items
+// below to the plain forms it understands.
+
+use std::ffi::{c_char, CString};
+use std::{slice, str};
+
+use aoc::solutions;
+
+// the answer is in `answer`
+pub const AOC_OK: i32 = 0;
+// the day is not solved
+pub const AOC_UNKNOWN_DAY: i32 = 1;
+// the day has no such part
+pub const AOC_UNKNOWN_PART: i32 = 2;
+// the input is not valid UTF-8, or a null pointer with a non-zero length
+pub const AOC_INVALID_INPUT: i32 = 3;
+// the solver panicked, typically on malformed input
+pub const AOC_PANIC: i32 = 4;
+
+// The result of aoc_solve and aoc_solve_year. `answer` is a NUL terminated string owned by the
+// caller, to be released with aoc_free. It holds the answer if `status` is AOC_OK and an error
+// message otherwise.
+#[repr(C)]
+pub struct AocAnswer {
+    pub status: i32,
+    pub answer: *mut c_char,
+}
+
+/// Solves one part (1 or 2) of a day of 2022 for the `len` bytes of input at `input`.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u8, part: u8, input: *const u8, len: usize) -> AocAnswer {
+    // SAFETY: passed on from the caller
+    unsafe { aoc_solve_year(solutions::DEFAULT_YEAR, day, part, input, len) }
+}
+
+/// Like aoc_solve, for a day of the given year.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+) -> AocAnswer {
+    let input = if len == 0 {
+        ""
+    } else if input.is_null() {
+        return answer(AOC_INVALID_INPUT, "input is a null pointer");
+    } else {
+        // SAFETY: the caller promises `len` readable bytes at `input`
+        match str::from_utf8(unsafe { slice::from_raw_parts(input, len) }) {
+            Ok(input) => input,
+            Err(e) => return answer(AOC_INVALID_INPUT, &format!("input is not UTF-8: {}", e)),
+        }
+    };
+    let Some(solution) = solutions::get(year, day) else {
+        return answer(
+            AOC_UNKNOWN_DAY,
+            &format!("day {} of {} is not solved", day, year),
+        );
+    };
+    if part == 0 || part as usize > solution.parts() {
+        return answer(
+            AOC_UNKNOWN_PART,
+            &format!("day {} of {} has no part {}", day, year, part),
+        );
+    }
+    match solutions::solve_part(solution, part as usize, input) {
+        Ok(a) => answer(AOC_OK, &a),
+        Err(e) => answer(AOC_PANIC, &e),
+    }
+}
+
+/// Releases an answer returned by aoc_solve. Null is ignored.
+///
+/// # Safety
+///
+/// `answer` must come from aoc_solve or aoc_solve_year and not have been released before.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(answer: *mut c_char) {
+    if !answer.is_null() {
+        // SAFETY: answers are only ever handed out by CString::into_raw
+        drop(unsafe { CString::from_raw(answer) });
+    }
+}
+
+fn answer(status: i32, text: &str) -> AocAnswer {
+    // solvers never produce NUL characters, but don't hand out a truncated answer if one does
+    let text = CString::new(text).unwrap_or_else(|_| CString::new("answer contains NUL").unwrap());
+    AocAnswer {
+        status,
+        answer: text.into_raw(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn solve(day: u8, part: u8, input: &[u8]) -> (i32, String) {
+        unsafe {
+            let result = aoc_solve(day, part, input.as_ptr(), input.len());
+            let answer = CStr::from_ptr(result.answer).to_str().unwrap().to_string();
+            aoc_free(result.answer);
+            (result.status, answer)
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = include_bytes!("../../day01/input-example");
+        assert_eq!(solve(1, 1, input), (AOC_OK, "24000".to_string()));
+        assert_eq!(solve(1, 2, input), (AOC_OK, "45000".to_string()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(solve(19, 1, b"").0, AOC_UNKNOWN_DAY);
+        let result = unsafe { aoc_solve_year(2015, 1, 1, b"(".as_ptr(), 1) };
+        assert_eq!(result.status, AOC_UNKNOWN_DAY);
+        unsafe { aoc_free(result.answer) };
+        assert_eq!(solve(25, 2, b"1=\n").0, AOC_UNKNOWN_PART);
+        assert_eq!(solve(1, 1, b"\xff\n").0, AOC_INVALID_INPUT);
+        assert_eq!(
+            solve(2, 1, b"A Q\n"),
+            (AOC_PANIC, "panicked: unknown input: A Q".to_string())
+        );
+        let result = unsafe { aoc_solve(1, 1, std::ptr::null(), 4) };
+        assert_eq!(result.status, AOC_INVALID_INPUT);
+        unsafe { aoc_free(result.answer) };
+    }
+}
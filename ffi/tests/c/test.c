@@ -0,0 +1,50 @@
+// Solves day01's example through the C API and checks the error paths. Exits non-zero and
+// says why on the first failed check.
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void check(uint8_t day, uint8_t part, const char *input, int32_t status,
+                  const char *expected) {
+    AocAnswer result = aoc_solve(day, part, (const uint8_t *)input, strlen(input));
+    if (result.status != status || (expected && strcmp(result.answer, expected) != 0)) {
+        fprintf(stderr, "day %d part %d: got %d \"%s\", expected %d \"%s\"\n", day, part,
+                result.status, result.answer, status, expected ? expected : "");
+        failures++;
+    }
+    aoc_free(result.answer);
+}
+
+int main(void) {
+    const char *example = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    check(1, 1, example, AOC_OK, "24000");
+    check(1, 2, example, AOC_OK, "45000");
+    check(25, 1, "1=\n2\n", AOC_OK, "10");
+    check(19, 1, "", AOC_UNKNOWN_DAY, "day 19 of 2022 is not solved");
+    check(25, 2, "1=\n", AOC_UNKNOWN_PART, NULL);
+    check(1, 1, "\xff\n", AOC_INVALID_INPUT, NULL);
+    check(2, 1, "A Q\n", AOC_PANIC, "panicked: unknown input: A Q");
+
+    AocAnswer result = aoc_solve_year(2022, 1, 1, (const uint8_t *)example, strlen(example));
+    if (result.status != AOC_OK || strcmp(result.answer, "24000") != 0) {
+        fprintf(stderr, "2022 day 1 part 1: got %d \"%s\"\n", result.status, result.answer);
+        failures++;
+    }
+    aoc_free(result.answer);
+    result = aoc_solve_year(2015, 1, 1, (const uint8_t *)"(", 1);
+    if (result.status != AOC_UNKNOWN_DAY) {
+        fprintf(stderr, "2015 day 1: got %d \"%s\"\n", result.status, result.answer);
+        failures++;
+    }
+    aoc_free(result.answer);
+    aoc_free(NULL);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
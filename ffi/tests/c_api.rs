@@ -0,0 +1,63 @@
+// Checks that the header checked in as include/aoc.h is up to date, then builds tests/c/test.c
+// against the generated header and the cdylib and runs it.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[test]
+fn test_header() {
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("aoc.h")).unwrap();
+    snapshot::check(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h"),
+        &generated,
+    );
+}
+
+// the shared library next to the test binary, in target/<profile>/deps, or one level up
+#[cfg(unix)]
+fn cdylib() -> PathBuf {
+    let name = format!(
+        "{}aoc_ffi{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    );
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    [deps.join(&name), deps.parent().unwrap().join(&name)]
+        .into_iter()
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| panic!("{} not found near {}", name, deps.display()))
+}
+
+#[cfg(unix)]
+#[test]
+fn test_c_program() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib = cdylib();
+    let exe = env::temp_dir().join(format!("aoc-ffi-test-{}", std::process::id()));
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(cc)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg(dir.join("tests/c/test.c"))
+        .arg(&lib)
+        .arg(format!("-Wl,-rpath,{}", lib.parent().unwrap().display()))
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .expect("cannot run the C compiler");
+    assert!(status.success(), "compiling test.c failed");
+
+    let output = Command::new(&exe).output().unwrap();
+    fs::remove_file(&exe).unwrap();
+    assert!(
+        output.status.success(),
+        "test.c failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
@@ -0,0 +1,152 @@
+// Golden snapshot tests for rendered output. A test compares a rendering with a file checked in
+// under the crate's snapshots directory:
+//
+//     snapshot::assert_snapshot!("example-route", route(include_str!("../input-example")));
+//
+// and fails with a line diff when they differ. Run the tests with AOC_UPDATE_SNAPSHOTS=1 to
+// write the renderings to their snapshots instead, then review the changes with git diff.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+pub const ENV_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+// Lines of unchanged context shown around each change.
+const CONTEXT: usize = 3;
+
+// Compares `actual` with the snapshot `name` in the calling crate's snapshots directory.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr $(,)?) => {
+        $crate::check(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/", $name),
+            &$actual,
+        )
+    };
+}
+
+#[track_caller]
+pub fn check(path: &str, actual: &str) {
+    let path = Path::new(path);
+    if env::var_os(ENV_VAR).is_some_and(|v| v != "0") {
+        if fs::read_to_string(path).is_ok_and(|expected| expected == actual) {
+            return;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .unwrap_or_else(|e| panic!("cannot create {}: {}", dir.display(), e));
+        }
+        fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("cannot write {}: {}", path.display(), e));
+        return;
+    }
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(e) => panic!(
+            "cannot read snapshot {}: {}\nrun with {}=1 to create it",
+            path.display(),
+            e,
+            ENV_VAR
+        ),
+    };
+    if expected != actual {
+        panic!(
+            "snapshot {} does not match (-snapshot +actual):\n{}run with {}=1 to update it",
+            path.display(),
+            diff(&expected, actual),
+            ENV_VAR
+        );
+    }
+}
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// A line diff in the style of `diff -u`, from the longest common subsequence of the lines.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let a: Vec<&str> = expected.split_inclusive('\n').collect();
+    let b: Vec<&str> = actual.split_inclusive('\n').collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push(Line::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(Line::Removed(a[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(b[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len())
+        .filter(|&k| !matches!(lines[k], Line::Same(_)))
+        .collect();
+    let mut out = String::new();
+    let mut shown = 0;
+    for (n, &k) in changed.iter().enumerate() {
+        let start = k.saturating_sub(CONTEXT).max(shown);
+        if start > shown {
+            out.push_str("...\n");
+        }
+        let next = changed.get(n + 1).copied().unwrap_or(lines.len());
+        let end = next.min(k + 1 + CONTEXT);
+        for line in &lines[start..end] {
+            let (prefix, text) = match line {
+                Line::Same(text) => (' ', text),
+                Line::Removed(text) => ('-', text),
+                Line::Added(text) => ('+', text),
+            };
+            let _ = write!(out, "{}{}", prefix, text);
+            if !text.ends_with('\n') {
+                out.push_str("\n\\ no newline at end\n");
+            }
+        }
+        shown = end;
+    }
+    if shown < lines.len() {
+        out.push_str("...\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\n";
+        let actual = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        assert_eq!(
+            diff(expected, actual),
+            " a\n-b\n+B\n c\n d\n e\n...\n g\n h\n i\n+j\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_trailing_newline() {
+        assert_eq!(diff("a\n", "a"), "-a\n+a\n\\ no newline at end\n");
+    }
+}
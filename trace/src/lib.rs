@@ -0,0 +1,166 @@
+// Structured trace events, written as JSON lines to the file named by the AOC_TRACE environment
+// variable. Without it tracing is off, and the event! and span! macros cost no more than a
+// relaxed atomic load: their fields are not even evaluated.
+//
+//     AOC_TRACE=trace.jsonl aoc 16
+//
+// Every record has a timestamp in microseconds since tracing started, the thread it came from
+// and the innermost open span on that thread:
+//
+//     {"us":1203,"thread":1,"span":1,"kind":"event","name":"day16.simulate","fields":{...}}
+//
+// Spans are opened with a "span_start" record and closed with a "span_end" record that has the
+// span's duration in "elapsed_us". Both carry the span's own "id"; their "span" is the enclosing
+// span.
+//
+// Tracing needs std for the clock, the file and thread locals. Without the default "std"
+// feature the crate is no_std, and tracing is always off.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "std")]
+mod log;
+#[cfg(feature = "std")]
+pub use log::{emit, enabled, Span, ENV_VAR};
+
+#[cfg(not(feature = "std"))]
+mod off;
+#[cfg(not(feature = "std"))]
+pub use off::{emit, enabled, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Uint(u64),
+    Bool(bool),
+    Str(String),
+}
+
+macro_rules! value_from {
+    ($($t:ty => $variant:ident),*) => {
+        $(impl From<$t> for Value {
+            fn from(v: $t) -> Value {
+                Value::$variant(v as _)
+            }
+        })*
+    };
+}
+
+value_from!(i32 => Int, i64 => Int, isize => Int, u8 => Uint, u16 => Uint, u32 => Uint, u64 => Uint, usize => Uint);
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Value {
+        Value::Bool(v)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Value {
+        Value::Str(v.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Value {
+        Value::Str(v)
+    }
+}
+
+// Quotes and escapes a string for JSON.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// Emits an event with the given fields if tracing is on, e.g.
+// `trace::event!("day14.sand", grains = i, floor = true)`.
+#[macro_export]
+macro_rules! event {
+    ($name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::enabled() {
+            $crate::emit(
+                "event",
+                $name,
+                &[$((stringify!($key), $crate::Value::from($value))),*],
+            );
+        }
+    };
+}
+
+// Opens a span that lasts until the returned guard is dropped, e.g.
+// `let _span = trace::span!("part", day = 1, part = 2);`.
+#[macro_export]
+macro_rules! span {
+    ($name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::enabled() {
+            $crate::Span::enter(
+                $name,
+                &[$((stringify!($key), $crate::Value::from($value))),*],
+            )
+        } else {
+            $crate::Span::disabled()
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log::record;
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_record() {
+        let fields = [
+            ("explored", Value::from(12usize)),
+            ("delta", Value::from(-3)),
+            ("valve", Value::from("A\"A")),
+        ];
+        assert_eq!(
+            record("event", "day16.simulate", Some(4), &[], &fields),
+            "\"span\":4,\"kind\":\"event\",\"name\":\"day16.simulate\",\
+             \"fields\":{\"explored\":12,\"delta\":-3,\"valve\":\"A\\\"A\"}}"
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\nb\\"), "\"a\\nb\\\\\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_disabled() {
+        // the test binary runs without AOC_TRACE, so nothing is evaluated
+        if env::var_os(ENV_VAR).is_none() {
+            let mut evaluated = false;
+            event!(
+                "test",
+                x = {
+                    evaluated = true;
+                    1
+                }
+            );
+            let _span = span!("test");
+            assert!(!evaluated);
+            assert!(!enabled());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// The std side of tracing: the AOC_TRACE file, the clock and the per-thread span stacks.
+
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::fs::File;
+use std::io::{LineWriter, Write};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use crate::{json_string, Value};
+
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+const UNKNOWN: u8 = 0;
+const OFF: u8 = 1;
+const ON: u8 = 2;
+
+static STATE: AtomicU8 = AtomicU8::new(UNKNOWN);
+static LOG: OnceLock<Option<(Instant, Mutex<LineWriter<File>>)>> = OnceLock::new();
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static THREAD: Cell<u64> = const { Cell::new(0) };
+    static SPANS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    match STATE.load(Ordering::Relaxed) {
+        OFF => false,
+        ON => true,
+        _ => init(),
+    }
+}
+
+#[cold]
+fn init() -> bool {
+    let log = LOG.get_or_init(|| {
+        let path = env::var_os(ENV_VAR)?;
+        match File::create(&path) {
+            Ok(file) => Some((Instant::now(), Mutex::new(LineWriter::new(file)))),
+            Err(e) => panic!("cannot create {}: {}", path.to_string_lossy(), e),
+        }
+    });
+    STATE.store(if log.is_some() { ON } else { OFF }, Ordering::Relaxed);
+    log.is_some()
+}
+
+pub fn emit(kind: &str, name: &str, fields: &[(&str, Value)]) {
+    let span = SPANS.with(|spans| spans.borrow().last().copied());
+    write(&record(kind, name, span, &[], fields));
+}
+
+fn write(record: &str) {
+    let Some(Some((start, log))) = LOG.get() else {
+        return;
+    };
+    let us = start.elapsed().as_micros();
+    let thread = THREAD.with(|t| {
+        if t.get() == 0 {
+            t.set(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        }
+        t.get()
+    });
+    let line = format!("{{\"us\":{},\"thread\":{},{}\n", us, thread, record);
+    let _ = log.lock().unwrap().write_all(line.as_bytes());
+}
+
+// Renders everything of a record except the timestamp and thread, without the opening brace.
+pub(crate) fn record(
+    kind: &str,
+    name: &str,
+    span: Option<u64>,
+    extra: &[(&str, Value)],
+    fields: &[(&str, Value)],
+) -> String {
+    let mut s = String::new();
+    match span {
+        Some(id) => s.push_str(&format!("\"span\":{},", id)),
+        None => s.push_str("\"span\":null,"),
+    }
+    s.push_str(&format!(
+        "\"kind\":{},\"name\":{}",
+        json_string(kind),
+        json_string(name)
+    ));
+    for (key, value) in extra {
+        s.push_str(&format!(",{}:{}", json_string(key), json(value)));
+    }
+    s.push_str(",\"fields\":{");
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        s.push_str(&format!("{}:{}", json_string(key), json(value)));
+    }
+    s.push_str("}}");
+    s
+}
+
+fn json(value: &Value) -> String {
+    match value {
+        Value::Int(v) => v.to_string(),
+        Value::Uint(v) => v.to_string(),
+        Value::Bool(v) => v.to_string(),
+        Value::Str(v) => json_string(v),
+    }
+}
+
+pub struct Span {
+    id: u64,
+    name: &'static str,
+    start: Option<Instant>,
+}
+
+impl Span {
+    pub fn enter(name: &'static str, fields: &[(&str, Value)]) -> Span {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let parent = SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            let parent = spans.last().copied();
+            spans.push(id);
+            parent
+        });
+        write(&record(
+            "span_start",
+            name,
+            parent,
+            &[("id", Value::Uint(id))],
+            fields,
+        ));
+        Span {
+            id,
+            name,
+            start: Some(Instant::now()),
+        }
+    }
+
+    pub fn disabled() -> Span {
+        Span {
+            id: 0,
+            name: "",
+            start: None,
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let parent = SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            spans.retain(|&id| id != self.id);
+            spans.last().copied()
+        });
+        let extra = [
+            ("id", Value::Uint(self.id)),
+            (
+                "elapsed_us",
+                Value::Uint(start.elapsed().as_micros() as u64),
+            ),
+        ];
+        write(&record("span_end", self.name, parent, &extra, &[]));
+    }
+}